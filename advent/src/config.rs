@@ -12,12 +12,16 @@ pub struct Config {
     /// Specifies which part is active for this advent day.
     #[arg(default_value_t = 1, long, short)]
     pub part: u8,
-}
 
-impl Config {
-    /// Returns a [Config] derived from the command line arguments supplied to
-    /// this program.
-    pub(crate) fn read_from_args() -> Config {
-        Config::parse()
-    }
+    /// Steps through this advent day's simulation in an interactive terminal
+    /// visualizer instead of printing the answer.
+    #[cfg(feature = "visualizer")]
+    #[arg(long)]
+    pub visualize: bool,
+
+    /// Specifies whether, and how, this advent day's simulation is rendered to
+    /// image files.
+    #[cfg(feature = "renderer")]
+    #[command(flatten)]
+    pub render_options: crate::renderer::RenderOptions,
 }
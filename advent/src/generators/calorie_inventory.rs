@@ -0,0 +1,66 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use super::GeneratedInput;
+
+/// Generated list of the calories carried by each elf (day 1).
+#[derive(Clone, Debug)]
+pub struct CalorieInventoryInput {
+    /// Calorie count of every item, grouped by the elf carrying it.
+    pub calorie_counts_by_elf: Vec<Vec<u32>>,
+}
+
+/// Returns a [Strategy] that generates [CalorieInventoryInput] instances.
+pub fn calorie_inventories() -> impl Strategy<Value = CalorieInventoryInput> {
+    vec(vec(1..=60_000u32, 1..=8), 1..=20).prop_map(|calorie_counts_by_elf| CalorieInventoryInput {
+        calorie_counts_by_elf,
+    })
+}
+
+impl GeneratedInput for CalorieInventoryInput {
+    fn encode(&self) -> String {
+        self.calorie_counts_by_elf
+            .iter()
+            .map(|calorie_counts| {
+                calorie_counts
+                    .iter()
+                    .map(|calorie_count| calorie_count.to_string())
+                    .collect::<Vec<String>>()
+                    .join("\n")
+            })
+            .collect::<Vec<String>>()
+            .join("\n\n")
+    }
+
+    fn answer(&self, part: u8) -> Option<String> {
+        let top_count = match part {
+            1 => 1,
+            2 => 3,
+            _ => return None,
+        };
+
+        let mut calorie_totals = self
+            .calorie_counts_by_elf
+            .iter()
+            .map(|calorie_counts| calorie_counts.iter().map(|&count| u64::from(count)).sum())
+            .collect::<Vec<u64>>();
+
+        // Repeatedly pluck out the largest remaining total.
+        let mut top_calorie_total_sum = 0;
+        for _ in 0..top_count {
+            let Some((index, calorie_total)) = calorie_totals
+                .iter()
+                .copied()
+                .enumerate()
+                .max_by_key(|(_, calorie_total)| *calorie_total)
+            else {
+                break;
+            };
+
+            top_calorie_total_sum += calorie_total;
+            calorie_totals.remove(index);
+        }
+
+        Some(top_calorie_total_sum.to_string())
+    }
+}
@@ -0,0 +1,129 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use super::GeneratedInput;
+
+/// Number of cycles it takes to draw every pixel of the CRT once.
+const CYCLE_COUNT: usize = 240;
+
+/// Number of pixels in each row of the CRT.
+const SCREEN_WIDTH: usize = 40;
+
+/// Generated CPU program (day 10).
+#[derive(Clone, Debug)]
+pub struct CpuProgramInput {
+    /// Every instruction in the program.
+    pub instructions: Vec<CpuInstruction>,
+}
+
+/// Enumerates every instruction a generated CPU program can contain.
+#[derive(Clone, Copy, Debug)]
+pub enum CpuInstruction {
+    /// Adds the integer to the register after two cycles.
+    AddX(i64),
+    /// Does nothing for one cycle.
+    NoOp,
+}
+
+/// Returns a [Strategy] that generates [CpuProgramInput] instances.
+///
+/// Programs run for exactly as many cycles as it takes to draw the CRT once.
+pub fn cpu_programs() -> impl Strategy<Value = CpuProgramInput> {
+    let instruction = prop_oneof![
+        Just(CpuInstruction::NoOp),
+        (-20..=20i64).prop_map(CpuInstruction::AddX),
+    ];
+
+    vec(instruction, CYCLE_COUNT).prop_map(|raw_instructions| {
+        let mut cycles = 0;
+        let mut instructions = vec![];
+
+        for instruction in raw_instructions {
+            let instruction = match instruction {
+                CpuInstruction::AddX(_) if cycles + 2 > CYCLE_COUNT => CpuInstruction::NoOp,
+                _ => instruction,
+            };
+
+            cycles += match instruction {
+                CpuInstruction::AddX(_) => 2,
+                CpuInstruction::NoOp => 1,
+            };
+            instructions.push(instruction);
+
+            if cycles == CYCLE_COUNT {
+                break;
+            }
+        }
+
+        CpuProgramInput { instructions }
+    })
+}
+
+impl CpuProgramInput {
+    /// Returns the value of the register during each cycle, in order.
+    pub fn register_values_naively(&self) -> Vec<i64> {
+        let mut register = 1;
+        let mut register_values = vec![];
+
+        for instruction in &self.instructions {
+            match instruction {
+                CpuInstruction::AddX(integer) => {
+                    register_values.push(register);
+                    register_values.push(register);
+
+                    register += integer;
+                }
+                CpuInstruction::NoOp => register_values.push(register),
+            }
+        }
+
+        register_values
+    }
+}
+
+impl GeneratedInput for CpuProgramInput {
+    fn encode(&self) -> String {
+        self.instructions
+            .iter()
+            .map(|instruction| match instruction {
+                CpuInstruction::AddX(integer) => format!("addx {}", integer),
+                CpuInstruction::NoOp => "noop".to_owned(),
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn answer(&self, part: u8) -> Option<String> {
+        let register_values = self.register_values_naively();
+
+        match part {
+            1 => Some(
+                [20, 60, 100, 140, 180, 220]
+                    .iter()
+                    .map(|&cycle| cycle as i64 * register_values[cycle - 1])
+                    .sum::<i64>()
+                    .to_string(),
+            ),
+            2 => Some(
+                register_values
+                    .chunks(SCREEN_WIDTH)
+                    .map(|row_register_values| {
+                        row_register_values
+                            .iter()
+                            .enumerate()
+                            .map(|(column, register_value)| {
+                                if (column as i64 - register_value).abs() <= 1 {
+                                    '#'
+                                } else {
+                                    '.'
+                                }
+                            })
+                            .collect::<String>()
+                    })
+                    .collect::<Vec<String>>()
+                    .join("\n"),
+            ),
+            _ => None,
+        }
+    }
+}
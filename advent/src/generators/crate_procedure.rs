@@ -0,0 +1,142 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::{select, Index};
+
+use super::GeneratedInput;
+
+/// Generated crate stack diagram followed by a rearrangement procedure (day 5).
+#[derive(Clone, Debug)]
+pub struct CrateProcedureInput {
+    /// Letters of the crates in each stack, from bottom to top.
+    pub crate_stacks: Vec<Vec<char>>,
+    /// `(number_of_crates, origin_index, destination_index)` of each move,
+    /// where indices are zero-based.
+    pub crate_moves: Vec<(usize, usize, usize)>,
+}
+
+/// Returns a [Strategy] that generates [CrateProcedureInput] instances.
+///
+/// Moves never empty a stack, so every stack has a top crate at the end.
+pub fn crate_procedures() -> impl Strategy<Value = CrateProcedureInput> {
+    let crate_letters = ('A'..='Z').collect::<Vec<char>>();
+
+    (
+        vec(vec(select(crate_letters), 1..=6), 1..=9),
+        vec((1..=6usize, any::<Index>(), any::<Index>()), 0..=20),
+    )
+        .prop_map(|(crate_stacks, raw_crate_moves)| {
+            let mut stack_heights = crate_stacks.iter().map(Vec::len).collect::<Vec<usize>>();
+            let mut crate_moves = vec![];
+
+            for (number_of_crates, origin, destination) in raw_crate_moves {
+                if stack_heights.len() < 2 {
+                    break;
+                }
+
+                let origin_index = origin.index(stack_heights.len());
+                let mut destination_index = destination.index(stack_heights.len() - 1);
+                if destination_index >= origin_index {
+                    destination_index += 1;
+                }
+
+                let number_of_crates = number_of_crates.min(stack_heights[origin_index] - 1);
+                if number_of_crates == 0 {
+                    continue;
+                }
+
+                stack_heights[origin_index] -= number_of_crates;
+                stack_heights[destination_index] += number_of_crates;
+
+                crate_moves.push((number_of_crates, origin_index, destination_index));
+            }
+
+            CrateProcedureInput {
+                crate_stacks,
+                crate_moves,
+            }
+        })
+}
+
+impl CrateProcedureInput {
+    /// Returns the crate stacks that result from executing every move, either
+    /// one crate at a time (`in_order == false`) or as a block.
+    pub fn execute_naively(&self, in_order: bool) -> Vec<Vec<char>> {
+        let mut crate_stacks = self.crate_stacks.clone();
+
+        for &(number_of_crates, origin_index, destination_index) in &self.crate_moves {
+            if in_order {
+                let split_index = crate_stacks[origin_index].len() - number_of_crates;
+                let moved_crates = crate_stacks[origin_index].split_off(split_index);
+
+                crate_stacks[destination_index].extend(moved_crates);
+            } else {
+                for _ in 0..number_of_crates {
+                    let moved_crate = crate_stacks[origin_index].pop().unwrap();
+
+                    crate_stacks[destination_index].push(moved_crate);
+                }
+            }
+        }
+
+        crate_stacks
+    }
+
+    /// Returns the crate stack diagram portion of this input.
+    pub fn encode_crate_stacks(&self) -> String {
+        let height = self.crate_stacks.iter().map(Vec::len).max().unwrap_or(0);
+
+        let crate_rows = (0..height).rev().map(|level| {
+            self.crate_stacks
+                .iter()
+                .map(|crate_stack| match crate_stack.get(level) {
+                    Some(letter) => format!("[{}]", letter),
+                    None => "   ".to_owned(),
+                })
+                .collect::<Vec<String>>()
+                .join(" ")
+        });
+
+        let label_row = (1..=self.crate_stacks.len())
+            .map(|label| format!(" {} ", label))
+            .collect::<Vec<String>>()
+            .join(" ");
+
+        crate_rows
+            .chain([label_row])
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+}
+
+impl GeneratedInput for CrateProcedureInput {
+    fn encode(&self) -> String {
+        let encoded_crate_moves = self
+            .crate_moves
+            .iter()
+            .map(|(number_of_crates, origin_index, destination_index)| {
+                format!(
+                    "move {} from {} to {}",
+                    number_of_crates,
+                    origin_index + 1,
+                    destination_index + 1
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n");
+
+        format!("{}\n\n{}", self.encode_crate_stacks(), encoded_crate_moves)
+    }
+
+    fn answer(&self, part: u8) -> Option<String> {
+        let crate_stacks = match part {
+            1 => self.execute_naively(false),
+            2 => self.execute_naively(true),
+            _ => return None,
+        };
+
+        crate_stacks
+            .iter()
+            .map(|crate_stack| crate_stack.last())
+            .collect::<Option<String>>()
+    }
+}
@@ -0,0 +1,53 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+
+use super::GeneratedInput;
+
+/// Generated stream of characters from the elven communication system (day 6).
+#[derive(Clone, Debug)]
+pub struct DataStreamInput {
+    /// Every character in the stream.
+    pub characters: Vec<char>,
+}
+
+/// Returns a [Strategy] that generates [DataStreamInput] instances.
+///
+/// Streams draw from alphabets of varying size so that markers are sometimes
+/// absent.
+pub fn data_streams() -> impl Strategy<Value = DataStreamInput> {
+    (1..=26usize).prop_flat_map(|alphabet_size| {
+        let alphabet = ('a'..='z').take(alphabet_size).collect::<Vec<char>>();
+
+        vec(select(alphabet), 0..=60).prop_map(|characters| DataStreamInput { characters })
+    })
+}
+
+impl DataStreamInput {
+    /// Returns the first index following a `span_length` long sequence of
+    /// non-repeating characters, comparing every pair in every window.
+    pub fn index_after_unique_span_naively(&self, span_length: usize) -> Option<usize> {
+        (span_length..=self.characters.len()).find(|&end| {
+            let window = &self.characters[end - span_length..end];
+
+            (0..window.len()).all(|i| (i + 1..window.len()).all(|j| window[i] != window[j]))
+        })
+    }
+}
+
+impl GeneratedInput for DataStreamInput {
+    fn encode(&self) -> String {
+        self.characters.iter().collect()
+    }
+
+    fn answer(&self, part: u8) -> Option<String> {
+        let span_length = match part {
+            1 => 4,
+            2 => 14,
+            _ => return None,
+        };
+
+        self.index_after_unique_span_naively(span_length)
+            .map(|index| index.to_string())
+    }
+}
@@ -0,0 +1,34 @@
+mod calorie_inventory;
+mod cpu_program;
+mod crate_procedure;
+mod data_stream;
+mod rope_moves;
+mod rucksacks;
+mod section_assignment_pairs;
+mod shell_transcript;
+mod strategy_guide;
+mod tree_grid;
+
+pub use calorie_inventory::*;
+pub use cpu_program::*;
+pub use crate_procedure::*;
+pub use data_stream::*;
+pub use rope_moves::*;
+pub use rucksacks::*;
+pub use section_assignment_pairs::*;
+pub use shell_transcript::*;
+pub use strategy_guide::*;
+pub use tree_grid::*;
+
+use std::fmt::Debug;
+
+/// Puzzle input generated alongside the answers that should be derived from
+/// it.
+pub trait GeneratedInput: Debug {
+    /// Returns this input encoded in the puzzle's textual format.
+    fn encode(&self) -> String;
+
+    /// Returns the answer to the specified `part` of the puzzle for this
+    /// input, worked out by brute force, or [None] if there is no answer.
+    fn answer(&self, part: u8) -> Option<String>;
+}
@@ -0,0 +1,79 @@
+use std::collections::HashSet;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+
+use super::GeneratedInput;
+
+/// Generated series of rope head motions (day 9).
+#[derive(Clone, Debug)]
+pub struct RopeMovesInput {
+    /// Direction ("U", "D", "L" or "R") and distance of each move.
+    pub rope_moves: Vec<(char, usize)>,
+}
+
+/// Returns a [Strategy] that generates [RopeMovesInput] instances.
+pub fn rope_moves() -> impl Strategy<Value = RopeMovesInput> {
+    vec((select(vec!['U', 'D', 'L', 'R']), 1..=10usize), 1..=30)
+        .prop_map(|rope_moves| RopeMovesInput { rope_moves })
+}
+
+impl RopeMovesInput {
+    /// Returns every position visited by the last of `knot_count` knots,
+    /// simulating one step at a time.
+    pub fn tail_positions_naively(&self, knot_count: usize) -> HashSet<(i32, i32)> {
+        let mut knots = vec![(0i32, 0i32); knot_count];
+        let mut tail_positions = HashSet::from([(0, 0)]);
+
+        for &(direction, distance) in &self.rope_moves {
+            let (step_x, step_y) = match direction {
+                'U' => (0, 1),
+                'D' => (0, -1),
+                'L' => (-1, 0),
+                _ => (1, 0),
+            };
+
+            for _ in 0..distance {
+                knots[0].0 += step_x;
+                knots[0].1 += step_y;
+
+                for i in 1..knot_count {
+                    let (leader_x, leader_y) = knots[i - 1];
+                    let (follower_x, follower_y) = knots[i];
+
+                    let is_touching =
+                        (leader_x - follower_x).abs() <= 1 && (leader_y - follower_y).abs() <= 1;
+                    if !is_touching {
+                        knots[i].0 += (leader_x - follower_x).signum();
+                        knots[i].1 += (leader_y - follower_y).signum();
+                    }
+                }
+
+                tail_positions.insert(knots[knot_count - 1]);
+            }
+        }
+
+        tail_positions
+    }
+}
+
+impl GeneratedInput for RopeMovesInput {
+    fn encode(&self) -> String {
+        self.rope_moves
+            .iter()
+            .map(|(direction, distance)| format!("{} {}", direction, distance))
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn answer(&self, part: u8) -> Option<String> {
+        let knot_count = match part {
+            1 => 2,
+            2 => 10,
+            _ => return None,
+        };
+
+        Some(self.tail_positions_naively(knot_count).len().to_string())
+    }
+}
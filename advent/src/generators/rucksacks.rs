@@ -0,0 +1,79 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::select;
+
+use super::GeneratedInput;
+
+/// Generated list of rucksack contents (day 3).
+#[derive(Clone, Debug)]
+pub struct RucksackInput {
+    /// Item types packed into each rucksack, in groups of three.
+    pub rucksacks: Vec<String>,
+}
+
+/// Returns a [Strategy] that generates [RucksackInput] instances.
+pub fn rucksacks() -> impl Strategy<Value = RucksackInput> {
+    let rucksack = (1..=12usize).prop_flat_map(|compartment_size| {
+        vec(select(item_types()), compartment_size * 2)
+            .prop_map(|item_types| item_types.into_iter().collect::<String>())
+    });
+
+    vec(vec(rucksack, 3), 1..=6).prop_map(|rucksack_groups| RucksackInput {
+        rucksacks: rucksack_groups.into_iter().flatten().collect(),
+    })
+}
+
+impl RucksackInput {
+    /// Returns the priority of the specified `item_type`, or [None] if it is
+    /// not a valid item type.
+    pub fn priority_naively(item_type: char) -> Option<u32> {
+        item_types()
+            .iter()
+            .position(|&other_item_type| other_item_type == item_type)
+            .map(|index| index as u32 + 1)
+    }
+}
+
+impl GeneratedInput for RucksackInput {
+    fn encode(&self) -> String {
+        self.rucksacks.join("\n")
+    }
+
+    fn answer(&self, part: u8) -> Option<String> {
+        let item_sets = match part {
+            1 => self
+                .rucksacks
+                .iter()
+                .map(|rucksack| {
+                    let (first_compartment, second_compartment) =
+                        rucksack.split_at(rucksack.len() / 2);
+
+                    vec![first_compartment, second_compartment]
+                })
+                .collect::<Vec<Vec<&str>>>(),
+            2 => self
+                .rucksacks
+                .chunks(3)
+                .map(|rucksack_group| rucksack_group.iter().map(String::as_str).collect())
+                .collect::<Vec<Vec<&str>>>(),
+            _ => return None,
+        };
+
+        let priority_total = item_sets
+            .iter()
+            .flat_map(|item_set| {
+                item_types().into_iter().filter(move |&item_type| {
+                    item_set.iter().all(|items| items.contains(item_type))
+                })
+            })
+            .filter_map(RucksackInput::priority_naively)
+            .sum::<u32>();
+
+        Some(priority_total.to_string())
+    }
+}
+
+/// Returns every legal item type in priority order.
+fn item_types() -> Vec<char> {
+    ('a'..='z').chain('A'..='Z').collect()
+}
@@ -0,0 +1,87 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use super::GeneratedInput;
+
+/// Generated list of section assignment pairs (day 4).
+#[derive(Clone, Debug)]
+pub struct SectionAssignmentPairsInput {
+    /// Inclusive `(from, to)` section id ranges assigned to each pair of elves.
+    pub section_assignment_pairs: Vec<[(u32, u32); 2]>,
+}
+
+/// Returns a [Strategy] that generates inclusive `(from, to)` section id
+/// ranges.
+pub fn section_assignments() -> impl Strategy<Value = (u32, u32)> {
+    (1..=99u32, 1..=99u32).prop_map(|(a, b)| (a.min(b), a.max(b)))
+}
+
+/// Returns a [Strategy] that generates [SectionAssignmentPairsInput]
+/// instances.
+pub fn section_assignment_pairs() -> impl Strategy<Value = SectionAssignmentPairsInput> {
+    vec([section_assignments(), section_assignments()], 1..=30).prop_map(
+        |section_assignment_pairs| SectionAssignmentPairsInput {
+            section_assignment_pairs,
+        },
+    )
+}
+
+impl SectionAssignmentPairsInput {
+    /// Returns `true` if every section in `a` is also in `b`, checking one
+    /// section at a time.
+    pub fn fully_contains_naively(a: (u32, u32), b: (u32, u32)) -> bool {
+        let sections_of_a = sections_of(a);
+
+        sections_of(b)
+            .iter()
+            .enumerate()
+            .all(|(section, &is_in_b)| !is_in_b || sections_of_a.get(section) == Some(&true))
+    }
+
+    /// Returns `true` if any section is in both `a` and `b`, checking one
+    /// section at a time.
+    pub fn overlaps_naively(a: (u32, u32), b: (u32, u32)) -> bool {
+        let sections_of_a = sections_of(a);
+
+        sections_of(b)
+            .iter()
+            .enumerate()
+            .any(|(section, &is_in_b)| is_in_b && sections_of_a.get(section) == Some(&true))
+    }
+}
+
+impl GeneratedInput for SectionAssignmentPairsInput {
+    fn encode(&self) -> String {
+        self.section_assignment_pairs
+            .iter()
+            .map(|[(a_from, a_to), (b_from, b_to)]| {
+                format!("{}-{},{}-{}", a_from, a_to, b_from, b_to)
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn answer(&self, part: u8) -> Option<String> {
+        let pair_count = self
+            .section_assignment_pairs
+            .iter()
+            .filter(|&&[a, b]| match part {
+                1 => {
+                    SectionAssignmentPairsInput::fully_contains_naively(a, b)
+                        || SectionAssignmentPairsInput::fully_contains_naively(b, a)
+                }
+                _ => SectionAssignmentPairsInput::overlaps_naively(a, b),
+            })
+            .count();
+
+        match part {
+            1 | 2 => Some(pair_count.to_string()),
+            _ => None,
+        }
+    }
+}
+
+/// Returns a bitmap, indexed by section id, of the sections in `assignment`.
+fn sections_of((from, to): (u32, u32)) -> Vec<bool> {
+    (0..=to).map(|section| section >= from).collect()
+}
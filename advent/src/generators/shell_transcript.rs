@@ -0,0 +1,119 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+use proptest::sample::Index;
+
+use super::GeneratedInput;
+
+/// Generated terminal session exploring a file system (day 7).
+#[derive(Clone, Debug)]
+pub struct ShellTranscriptInput {
+    /// Every directory, as `(name, parent_index)`; the first is the root.
+    pub directories: Vec<(String, Option<usize>)>,
+    /// Every file, as `(name, size, directory_index)`.
+    pub files: Vec<(String, u32, usize)>,
+}
+
+/// Returns a [Strategy] that generates [ShellTranscriptInput] instances.
+pub fn shell_transcripts() -> impl Strategy<Value = ShellTranscriptInput> {
+    vec((any::<Index>(), any::<bool>(), 1..=200_000u32), 0..=40).prop_map(|raw_entries| {
+        let mut directories = vec![("/".to_owned(), None)];
+        let mut files = vec![];
+
+        for (i, (parent, is_directory, size)) in raw_entries.into_iter().enumerate() {
+            let parent_index = parent.index(directories.len());
+
+            if is_directory {
+                directories.push((format!("d{}", i), Some(parent_index)));
+            } else {
+                files.push((format!("f{}.txt", i), size, parent_index));
+            }
+        }
+
+        ShellTranscriptInput { directories, files }
+    })
+}
+
+impl ShellTranscriptInput {
+    /// Returns the total size of every file within the directory at
+    /// `directory_index`, walking up from each file to look for it.
+    pub fn directory_size_naively(&self, directory_index: usize) -> u32 {
+        self.files
+            .iter()
+            .filter(|(_, _, file_directory_index)| {
+                let mut maybe_ancestor_index = Some(*file_directory_index);
+                while let Some(ancestor_index) = maybe_ancestor_index {
+                    if ancestor_index == directory_index {
+                        return true;
+                    }
+
+                    maybe_ancestor_index = self.directories[ancestor_index].1;
+                }
+
+                false
+            })
+            .map(|(_, size, _)| size)
+            .sum()
+    }
+
+    /// Appends the commands that explore the directory at `directory_index`
+    /// to `lines`.
+    fn furnish_lines(&self, directory_index: usize, lines: &mut Vec<String>) {
+        let subdirectory_indices = (0..self.directories.len())
+            .filter(|&index| self.directories[index].1 == Some(directory_index))
+            .collect::<Vec<usize>>();
+
+        lines.push("$ ls".to_owned());
+        for &subdirectory_index in &subdirectory_indices {
+            lines.push(format!("dir {}", self.directories[subdirectory_index].0));
+        }
+        for (name, size, _) in self
+            .files
+            .iter()
+            .filter(|(_, _, file_directory_index)| *file_directory_index == directory_index)
+        {
+            lines.push(format!("{} {}", size, name));
+        }
+
+        for subdirectory_index in subdirectory_indices {
+            lines.push(format!("$ cd {}", self.directories[subdirectory_index].0));
+            self.furnish_lines(subdirectory_index, lines);
+            lines.push("$ cd ..".to_owned());
+        }
+    }
+}
+
+impl GeneratedInput for ShellTranscriptInput {
+    fn encode(&self) -> String {
+        let mut lines = vec!["$ cd /".to_owned()];
+
+        self.furnish_lines(0, &mut lines);
+
+        lines.join("\n")
+    }
+
+    fn answer(&self, part: u8) -> Option<String> {
+        let directory_sizes = (0..self.directories.len())
+            .map(|directory_index| self.directory_size_naively(directory_index))
+            .collect::<Vec<u32>>();
+
+        match part {
+            1 => Some(
+                directory_sizes
+                    .iter()
+                    .filter(|&&size| size <= 100_000)
+                    .sum::<u32>()
+                    .to_string(),
+            ),
+            2 => {
+                let remaining_space = 70_000_000 - directory_sizes[0];
+
+                directory_sizes
+                    .iter()
+                    .filter(|&&size| remaining_space + size >= 30_000_000)
+                    .min()
+                    .map(|size| size.to_string())
+            }
+            _ => None,
+        }
+    }
+}
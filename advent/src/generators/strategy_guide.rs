@@ -0,0 +1,83 @@
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use super::GeneratedInput;
+
+/// Generated rock paper scissors strategy guide (day 2).
+#[derive(Clone, Debug)]
+pub struct StrategyGuideInput {
+    /// Column indices (`0..3`) of each round: the opponent's column ("A", "B",
+    /// "C") followed by the second column ("X", "Y", "Z").
+    pub rounds: Vec<(u8, u8)>,
+}
+
+/// Returns a [Strategy] that generates [StrategyGuideInput] instances.
+pub fn strategy_guides() -> impl Strategy<Value = StrategyGuideInput> {
+    vec((0..3u8, 0..3u8), 1..=30).prop_map(|rounds| StrategyGuideInput { rounds })
+}
+
+impl StrategyGuideInput {
+    /// Returns the score for playing `my_shape` against `opponent_shape`,
+    /// where shapes are rock (`0`), paper (`1`) and scissors (`2`).
+    pub fn score_naively(my_shape: u8, opponent_shape: u8) -> u32 {
+        let outcome_score = if beats(my_shape, opponent_shape) {
+            6
+        } else if beats(opponent_shape, my_shape) {
+            0
+        } else {
+            3
+        };
+
+        u32::from(my_shape) + 1 + outcome_score
+    }
+}
+
+impl GeneratedInput for StrategyGuideInput {
+    fn encode(&self) -> String {
+        self.rounds
+            .iter()
+            .map(|(first_column, second_column)| {
+                format!(
+                    "{} {}",
+                    char::from(b'A' + first_column),
+                    char::from(b'X' + second_column)
+                )
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn answer(&self, part: u8) -> Option<String> {
+        let total_score = self
+            .rounds
+            .iter()
+            .map(|&(opponent_shape, second_column)| match part {
+                1 => Some(StrategyGuideInput::score_naively(
+                    second_column,
+                    opponent_shape,
+                )),
+                2 => {
+                    // Try every shape until one yields the desired outcome:
+                    // lose ("X"), draw ("Y") or win ("Z").
+                    let my_shape = (0..3)
+                        .find(|&my_shape| match second_column {
+                            0 => beats(opponent_shape, my_shape),
+                            1 => my_shape == opponent_shape,
+                            _ => beats(my_shape, opponent_shape),
+                        })
+                        .unwrap();
+
+                    Some(StrategyGuideInput::score_naively(my_shape, opponent_shape))
+                }
+                _ => None,
+            })
+            .sum::<Option<u32>>()?;
+
+        Some(total_score.to_string())
+    }
+}
+
+/// Returns `true` if shape `a` beats shape `b`.
+fn beats(a: u8, b: u8) -> bool {
+    matches!((a, b), (0, 2) | (1, 0) | (2, 1))
+}
@@ -0,0 +1,112 @@
+use std::collections::HashSet;
+
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+use super::GeneratedInput;
+
+/// Generated grid of tree heights (day 8).
+#[derive(Clone, Debug)]
+pub struct TreeGridInput {
+    /// Height of every tree, row by row.
+    pub tree_height_rows: Vec<Vec<u32>>,
+}
+
+/// Returns a [Strategy] that generates [TreeGridInput] instances.
+pub fn tree_grids() -> impl Strategy<Value = TreeGridInput> {
+    (3..=10usize, 3..=10usize).prop_flat_map(|(row_count, column_count)| {
+        vec(vec(0..=9u32, column_count), row_count)
+            .prop_map(|tree_height_rows| TreeGridInput { tree_height_rows })
+    })
+}
+
+impl TreeGridInput {
+    /// Returns the `(x, y)` co-ordinates of trees that are visible from the
+    /// outside, looking along every line of sight from every tree.
+    pub fn visible_trees_naively(&self) -> HashSet<(usize, usize)> {
+        let mut visible_trees = HashSet::new();
+
+        for (y, tree_height_row) in self.tree_height_rows.iter().enumerate() {
+            for (x, &tree_height) in tree_height_row.iter().enumerate() {
+                let is_visible = self.lines_of_sight(x, y).iter().any(|line_of_sight| {
+                    line_of_sight
+                        .iter()
+                        .all(|&other_height| other_height < tree_height)
+                });
+
+                if is_visible {
+                    visible_trees.insert((x, y));
+                }
+            }
+        }
+
+        visible_trees
+    }
+
+    /// Returns the scenic score of the tree at `(x, y)`, counting the trees it
+    /// can see in every direction.
+    pub fn scenic_score_naively(&self, x: usize, y: usize) -> usize {
+        let tree_height = self.tree_height_rows[y][x];
+
+        self.lines_of_sight(x, y)
+            .iter()
+            .map(|line_of_sight| {
+                match line_of_sight
+                    .iter()
+                    .position(|&other_height| other_height >= tree_height)
+                {
+                    Some(blocking_index) => blocking_index + 1,
+                    None => line_of_sight.len(),
+                }
+            })
+            .product()
+    }
+
+    /// Returns the heights of the trees between `(x, y)` and each edge of the
+    /// grid, nearest first.
+    fn lines_of_sight(&self, x: usize, y: usize) -> Vec<Vec<u32>> {
+        let row = &self.tree_height_rows[y];
+        let column = self
+            .tree_height_rows
+            .iter()
+            .map(|tree_height_row| tree_height_row[x])
+            .collect::<Vec<u32>>();
+
+        vec![
+            column[..y].iter().rev().copied().collect(),
+            row[x + 1..].to_vec(),
+            column[y + 1..].to_vec(),
+            row[..x].iter().rev().copied().collect(),
+        ]
+    }
+}
+
+impl GeneratedInput for TreeGridInput {
+    fn encode(&self) -> String {
+        self.tree_height_rows
+            .iter()
+            .map(|tree_height_row| {
+                tree_height_row
+                    .iter()
+                    .map(|tree_height| tree_height.to_string())
+                    .collect::<String>()
+            })
+            .collect::<Vec<String>>()
+            .join("\n")
+    }
+
+    fn answer(&self, part: u8) -> Option<String> {
+        match part {
+            1 => Some(self.visible_trees_naively().len().to_string()),
+            2 => self
+                .tree_height_rows
+                .iter()
+                .enumerate()
+                .flat_map(|(y, tree_height_row)| (0..tree_height_row.len()).map(move |x| (x, y)))
+                .map(|(x, y)| self.scenic_score_naively(x, y))
+                .max()
+                .map(|scenic_score| scenic_score.to_string()),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,452 @@
+use std::fmt::{Debug, Display};
+
+/// Value with an immediate successor and predecessor, like an integer, so that
+/// intervals of them can be counted, found adjacent, and subtracted.
+pub trait Discrete: Copy + Ord {
+    /// Returns the value right after this one, if there is one.
+    fn successor(self) -> Option<Self>;
+
+    /// Returns the value right before this one, if there is one.
+    fn predecessor(self) -> Option<Self>;
+
+    /// Returns how many steps apart `from` and `to` are.
+    fn distance(from: Self, to: Self) -> u128;
+}
+
+/// Implements [Discrete] for each of the specified integer types.
+macro_rules! impl_discrete {
+    ($($integer:ty),*) => {
+        $(
+            impl Discrete for $integer {
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+
+                fn distance(from: Self, to: Self) -> u128 {
+                    from.abs_diff(to) as u128
+                }
+            }
+        )*
+    };
+}
+
+impl_discrete!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize);
+
+/// Inclusive range of values, from [Interval::start] through [Interval::end].
+///
+/// An [Interval] is never empty: its start never comes after its end.
+#[derive(Clone, Copy, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Interval<T> {
+    /// First value included in this [Interval].
+    start: T,
+    /// Last value included in this [Interval].
+    end: T,
+}
+
+impl<T: Ord> Interval<T> {
+    /// Creates and returns a new [Interval] from `start` through `end`, unless
+    /// `start` comes after `end`.
+    pub fn new(start: T, end: T) -> Option<Interval<T>> {
+        if start <= end {
+            Some(Interval { start, end })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the first value included in this [Interval].
+    pub fn start(&self) -> &T {
+        &self.start
+    }
+
+    /// Returns the last value included in this [Interval].
+    pub fn end(&self) -> &T {
+        &self.end
+    }
+
+    /// Returns `true` if this [Interval] includes `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        self.start <= *value && *value <= self.end
+    }
+
+    /// Returns `true` if this [Interval] includes every value of `other`.
+    pub fn fully_contains(&self, other: &Interval<T>) -> bool {
+        self.start <= other.start && other.end <= self.end
+    }
+
+    /// Returns `true` if this [Interval] and `other` have any value in common.
+    pub fn overlaps(&self, other: &Interval<T>) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+impl<T: Clone + Ord> Interval<T> {
+    /// Returns the values that this [Interval] and `other` have in common, if
+    /// there are any.
+    pub fn intersection(&self, other: &Interval<T>) -> Option<Interval<T>> {
+        Interval::new(
+            self.start.clone().max(other.start.clone()),
+            self.end.clone().min(other.end.clone()),
+        )
+    }
+
+    /// Returns the smallest [Interval] that includes both this one and
+    /// `other`, along with any values between them.
+    pub fn hull(&self, other: &Interval<T>) -> Interval<T> {
+        Interval {
+            start: self.start.clone().min(other.start.clone()),
+            end: self.end.clone().max(other.end.clone()),
+        }
+    }
+}
+
+impl<T: Discrete> Interval<T> {
+    /// Returns how many values this [Interval] includes.
+    pub fn length(&self) -> u128 {
+        T::distance(self.start, self.end) + 1
+    }
+
+    /// Returns `true` if this [Interval] ends right before `other` starts, or
+    /// vice versa, so that together they cover a single range of values.
+    pub fn is_adjacent_to(&self, other: &Interval<T>) -> bool {
+        self.end.successor() == Some(other.start) || other.end.successor() == Some(self.start)
+    }
+
+    /// Returns every value included in this [Interval] or `other`.
+    pub fn union(&self, other: &Interval<T>) -> IntervalSet<T> {
+        [*self, *other].into_iter().collect()
+    }
+
+    /// Returns every value included in this [Interval] but not in `other`.
+    pub fn difference(&self, other: &Interval<T>) -> IntervalSet<T> {
+        let Some(overlap) = self.intersection(other) else {
+            return IntervalSet::from(*self);
+        };
+
+        let before = overlap
+            .start
+            .predecessor()
+            .and_then(|end| Interval::new(self.start, end));
+        let after = overlap
+            .end
+            .successor()
+            .and_then(|start| Interval::new(start, self.end));
+
+        before.into_iter().chain(after).collect()
+    }
+}
+
+impl<T: Debug> Debug for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:?}..={:?}", self.start, self.end)
+    }
+}
+
+impl<T: Display> Display for Interval<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}-{}", self.start, self.end)
+    }
+}
+
+/// Set of values stored as the fewest [Interval]s that cover them, in order.
+///
+/// Overlapping and adjacent intervals are merged as they are inserted, so no
+/// two intervals of an [IntervalSet] overlap or touch.
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct IntervalSet<T> {
+    /// Disjoint, non-adjacent intervals of this set, in ascending order.
+    intervals: Vec<Interval<T>>,
+}
+
+impl<T: Discrete> IntervalSet<T> {
+    /// Creates and returns a new, empty [IntervalSet].
+    pub fn new() -> IntervalSet<T> {
+        IntervalSet { intervals: vec![] }
+    }
+
+    /// Returns the disjoint, non-adjacent intervals of this set, in ascending
+    /// order.
+    pub fn intervals(&self) -> &[Interval<T>] {
+        &self.intervals
+    }
+
+    /// Returns `true` if this set has no values.
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Returns how many values this set has.
+    pub fn len(&self) -> u128 {
+        self.intervals.iter().map(Interval::length).sum()
+    }
+
+    /// Returns `true` if this set has `value`.
+    pub fn contains(&self, value: &T) -> bool {
+        // The first interval that does not end before `value` is the only one
+        // that could include it.
+        let i = self
+            .intervals
+            .partition_point(|interval| interval.end < *value);
+
+        self.intervals
+            .get(i)
+            .is_some_and(|interval| interval.contains(value))
+    }
+
+    /// Adds every value of `interval` to this set.
+    pub fn insert(&mut self, interval: Interval<T>) {
+        // Intervals that end at least a value before `interval` starts are
+        // untouched, as are those that start at least a value after it ends.
+        let first = self.intervals.partition_point(|other| {
+            other.end < interval.start && !other.is_adjacent_to(&interval)
+        });
+        let last = self.intervals.partition_point(|other| {
+            other.start <= interval.end || other.is_adjacent_to(&interval)
+        });
+
+        let merged = self.intervals[first..last]
+            .iter()
+            .fold(interval, |merged, other| merged.hull(other));
+
+        self.intervals.splice(first..last, [merged]);
+    }
+
+    /// Returns every value in this set or `other`.
+    pub fn union(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut union = self.clone();
+        union.extend(other.intervals.iter().copied());
+
+        union
+    }
+
+    /// Returns every value in both this set and `other`.
+    pub fn intersection(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut intersection = IntervalSet::new();
+
+        // Walk both sets in order, always advancing past whichever interval
+        // ends first, since it cannot overlap anything further along.
+        let (mut i, mut j) = (0, 0);
+        while let (Some(a), Some(b)) = (self.intervals.get(i), other.intervals.get(j)) {
+            if let Some(overlap) = a.intersection(b) {
+                intersection.intervals.push(overlap);
+            }
+
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        intersection
+    }
+
+    /// Returns every value in this set but not in `other`.
+    pub fn difference(&self, other: &IntervalSet<T>) -> IntervalSet<T> {
+        let mut difference = IntervalSet::new();
+
+        let mut j = 0;
+        for interval in &self.intervals {
+            while other
+                .intervals
+                .get(j)
+                .is_some_and(|other_interval| other_interval.end < interval.start)
+            {
+                j += 1;
+            }
+
+            // Carve each overlapping interval of `other` out of what remains
+            // of `interval`, front to back.
+            let mut remainder = Some(*interval);
+            for other_interval in &other.intervals[j..] {
+                let Some(rest) = remainder else {
+                    break;
+                };
+                if other_interval.start > rest.end {
+                    break;
+                }
+
+                difference.intervals.extend(
+                    other_interval
+                        .start
+                        .predecessor()
+                        .and_then(|end| Interval::new(rest.start, end)),
+                );
+
+                remainder = other_interval
+                    .end
+                    .successor()
+                    .and_then(|start| Interval::new(start, rest.end));
+            }
+
+            difference.intervals.extend(remainder);
+        }
+
+        difference
+    }
+}
+
+impl<T: Discrete> Default for IntervalSet<T> {
+    fn default() -> Self {
+        IntervalSet::new()
+    }
+}
+
+impl<T: Discrete> Extend<Interval<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = Interval<T>>>(&mut self, intervals: I) {
+        for interval in intervals {
+            self.insert(interval);
+        }
+    }
+}
+
+impl<T: Discrete> From<Interval<T>> for IntervalSet<T> {
+    fn from(interval: Interval<T>) -> Self {
+        IntervalSet {
+            intervals: vec![interval],
+        }
+    }
+}
+
+impl<T: Discrete> FromIterator<Interval<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = Interval<T>>>(intervals: I) -> Self {
+        let mut interval_set = IntervalSet::new();
+        interval_set.extend(intervals);
+
+        interval_set
+    }
+}
+
+impl<T: Debug> Debug for IntervalSet<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(&self.intervals).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::collection::vec;
+    use proptest::prelude::*;
+
+    /// Largest value that generated intervals include.
+    const MAX_VALUE: u8 = 63;
+
+    /// Returns a [Strategy] that generates intervals of small numbers.
+    fn intervals() -> impl Strategy<Value = Interval<u8>> {
+        (0..=MAX_VALUE, 0..=MAX_VALUE).prop_map(|(a, b)| Interval::new(a.min(b), a.max(b)).unwrap())
+    }
+
+    /// Returns a [Strategy] that generates sets of small numbers.
+    fn interval_sets() -> impl Strategy<Value = IntervalSet<u8>> {
+        vec(intervals(), 0..6).prop_map(IntervalSet::from_iter)
+    }
+
+    /// Returns a bitmap, indexed by value, of the values in `interval_set`,
+    /// checking one value at a time.
+    fn bitmap(interval_set: &IntervalSet<u8>) -> Vec<bool> {
+        (0..=MAX_VALUE)
+            .map(|value| interval_set.contains(&value))
+            .collect()
+    }
+
+    /// Returns a bitmap, indexed by value, of the values in `interval`.
+    fn interval_bitmap(interval: &Interval<u8>) -> Vec<bool> {
+        (0..=MAX_VALUE)
+            .map(|value| interval.start <= value && value <= interval.end)
+            .collect()
+    }
+
+    /// Returns a bitmap combining each value of `a` and `b` with `operation`.
+    fn combine(a: &[bool], b: &[bool], operation: impl Fn(bool, bool) -> bool) -> Vec<bool> {
+        a.iter().zip(b).map(|(&a, &b)| operation(a, b)).collect()
+    }
+
+    /// Returns `true` if no two intervals of `interval_set` overlap or touch,
+    /// and they are in ascending order.
+    fn is_normalized(interval_set: &IntervalSet<u8>) -> bool {
+        interval_set
+            .intervals()
+            .windows(2)
+            .all(|pair| u32::from(pair[0].end) + 1 < u32::from(pair[1].start))
+    }
+
+    proptest! {
+        #[test]
+        fn interval_algebra_agrees_with_bitmaps(a in intervals(), b in intervals()) {
+            let (a_bitmap, b_bitmap) = (interval_bitmap(&a), interval_bitmap(&b));
+
+            prop_assert_eq!(
+                a.intersection(&b).map(|overlap| interval_bitmap(&overlap)),
+                Some(combine(&a_bitmap, &b_bitmap, |a, b| a && b))
+                    .filter(|overlap| overlap.contains(&true))
+            );
+            prop_assert_eq!(bitmap(&a.union(&b)), combine(&a_bitmap, &b_bitmap, |a, b| a || b));
+            prop_assert_eq!(
+                bitmap(&a.difference(&b)),
+                combine(&a_bitmap, &b_bitmap, |a, b| a && !b)
+            );
+            prop_assert_eq!(
+                a.length(),
+                a_bitmap.iter().filter(|&&is_in_a| is_in_a).count() as u128
+            );
+            prop_assert_eq!(a.overlaps(&b), a.intersection(&b).is_some());
+            prop_assert_eq!(
+                a.is_adjacent_to(&b),
+                !a.overlaps(&b) && a.union(&b).intervals().len() == 1
+            );
+            prop_assert_eq!(
+                a.fully_contains(&b),
+                combine(&a_bitmap, &b_bitmap, |a, b| a || !b).iter().all(|&value| value)
+            );
+        }
+
+        #[test]
+        fn interval_set_algebra_agrees_with_bitmaps(a in interval_sets(), b in interval_sets()) {
+            let (a_bitmap, b_bitmap) = (bitmap(&a), bitmap(&b));
+
+            for (result, expected) in [
+                (a.union(&b), combine(&a_bitmap, &b_bitmap, |a, b| a || b)),
+                (a.intersection(&b), combine(&a_bitmap, &b_bitmap, |a, b| a && b)),
+                (a.difference(&b), combine(&a_bitmap, &b_bitmap, |a, b| a && !b)),
+            ] {
+                prop_assert!(is_normalized(&result), "{:?}", result);
+                prop_assert_eq!(bitmap(&result), expected);
+            }
+
+            prop_assert!(is_normalized(&a));
+            prop_assert_eq!(a.len(), a_bitmap.iter().filter(|&&is_in_a| is_in_a).count() as u128);
+            prop_assert_eq!(a.is_empty(), !a_bitmap.contains(&true));
+        }
+    }
+
+    #[test]
+    fn insert_merges_adjacent_intervals() {
+        let interval_set = [(1, 2), (6, 8), (3, 4)]
+            .into_iter()
+            .map(|(start, end)| Interval::new(start, end).unwrap())
+            .collect::<IntervalSet<u32>>();
+
+        assert_eq!(
+            interval_set.intervals(),
+            [Interval::new(1, 4).unwrap(), Interval::new(6, 8).unwrap()]
+        );
+    }
+
+    #[test]
+    fn extremes_do_not_overflow() {
+        let everything = Interval::new(u64::MIN, u64::MAX).unwrap();
+        let nothing_but_zero = Interval::new(0, 0).unwrap();
+
+        assert_eq!(everything.length(), 1 << 64);
+        assert_eq!(
+            everything.difference(&nothing_but_zero).intervals(),
+            [Interval::new(1, u64::MAX).unwrap()]
+        );
+        assert!(everything.difference(&everything).is_empty());
+    }
+}
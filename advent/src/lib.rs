@@ -6,12 +6,26 @@ extern crate tokio;
 mod config;
 mod data_file_name;
 mod data_source;
+#[cfg(feature = "generators")]
+pub mod generators;
+pub mod interval;
+#[cfg(feature = "renderer")]
+pub mod renderer;
+mod solution;
+pub mod testing;
+#[cfg(feature = "visualizer")]
+pub mod visualizer;
 
 pub use config::Config;
 pub use data_file_name::*;
 pub use data_source::DataSource;
+pub use solution::Solution;
+
+#[cfg(feature = "generators")]
+pub use proptest;
 
 use anyhow::{anyhow, Context, Ok, Result};
+use clap::Parser;
 use std::env::current_dir;
 use std::path::PathBuf;
 use tokio::fs::File;
@@ -19,9 +33,17 @@ use tokio::io::AsyncReadExt;
 
 /// Starts this advent day, returning the specified [Config].
 pub fn begin() -> Config {
+    begin_with_args()
+}
+
+/// Starts this advent day, returning the specified command line arguments.
+///
+/// This is meant for advent days that accept more arguments than a [Config]
+/// offers, which they can add by flattening one into their own [Parser].
+pub fn begin_with_args<A: Parser>() -> A {
     env_logger::init();
 
-    Config::read_from_args()
+    A::parse()
 }
 
 /// Reads the input data for the advent day configured by `config`.
@@ -0,0 +1,307 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs::{create_dir_all, File};
+use std::io::BufWriter;
+use std::path::{Path, PathBuf};
+
+use anyhow::{anyhow, Context, Result};
+use clap::Args;
+
+/// Red, green, and blue intensities of a single pixel.
+pub type Color = [u8; 3];
+
+/// One step of a simulation, able to draw itself as a grid of pixels.
+pub trait Picture {
+    /// Returns a [Canvas] depicting this [Picture].
+    fn draw(&self) -> Canvas;
+}
+
+/// Grid of pixels that a [Picture] draws onto.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Canvas {
+    /// How many columns of pixels this [Canvas] has.
+    width: usize,
+    /// How many rows of pixels this [Canvas] has.
+    height: usize,
+    /// Pixels on this [Canvas], row by row.
+    pixels: Vec<Color>,
+}
+
+impl Canvas {
+    /// Creates and returns a new [Canvas] filled with `background`.
+    pub fn new(width: usize, height: usize, background: Color) -> Canvas {
+        Canvas {
+            width,
+            height,
+            pixels: vec![background; width * height],
+        }
+    }
+
+    /// Returns how many columns of pixels this [Canvas] has.
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Returns how many rows of pixels this [Canvas] has.
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Returns the color of the pixel at column `x` and row `y`, if there is
+    /// one.
+    pub fn get(&self, x: usize, y: usize) -> Option<Color> {
+        if x < self.width && y < self.height {
+            Some(self.pixels[y * self.width + x])
+        } else {
+            None
+        }
+    }
+
+    /// Colors the pixel at column `x` and row `y`; pixels beyond the edges of
+    /// this [Canvas] are ignored.
+    pub fn set(&mut self, x: usize, y: usize, color: Color) {
+        if x < self.width && y < self.height {
+            self.pixels[y * self.width + x] = color;
+        }
+    }
+
+    /// Returns a copy of this [Canvas] that is `width` by `height` pixels,
+    /// with every pixel blown up into a `scale` by `scale` square.
+    ///
+    /// Anything beyond `width` and `height` is cropped, and any space left
+    /// over is filled with `background`.
+    fn resized(&self, width: usize, height: usize, scale: usize, background: Color) -> Canvas {
+        let mut canvas = Canvas::new(width, height, background);
+
+        for y in 0..height {
+            for x in 0..width {
+                if let Some(color) = self.get(x / scale, y / scale) {
+                    canvas.set(x, y, color);
+                }
+            }
+        }
+
+        canvas
+    }
+}
+
+/// Settings that decide how pictures are written out.
+#[derive(Args, Clone, Debug, Eq, PartialEq)]
+pub struct RenderOptions {
+    /// Renders this advent day's simulation to the specified path instead of
+    /// printing the answer; paths ending in `.gif` get an animated GIF, and any
+    /// other path gets a directory of numbered PNG frames.
+    #[arg(long = "render")]
+    pub path: Option<PathBuf>,
+
+    /// How many hundredths of a second each frame of an animated GIF is shown.
+    #[arg(default_value_t = 10, long = "render-frame-delay")]
+    pub frame_delay: u16,
+
+    /// Only every Nth frame is rendered, along with the last one.
+    #[arg(default_value_t = 1, long = "render-frame-stride")]
+    pub frame_stride: usize,
+
+    /// How many pixels wide and tall each pixel of a picture is drawn.
+    #[arg(default_value_t = 4, long = "render-scale")]
+    pub scale: usize,
+}
+
+/// Writes the specified `pictures` to `path`.
+///
+/// If `path` ends in `.gif`, the pictures are written as the frames of a
+/// single looping animated GIF; otherwise `path` is treated as a directory
+/// and each picture is written to a numbered PNG file inside of it.
+pub fn render<P: Picture>(pictures: &[P], path: &Path, options: &RenderOptions) -> Result<()> {
+    if options.scale == 0 || options.frame_stride == 0 {
+        return Err(anyhow!("Scale and frame stride must both be at least 1"));
+    }
+
+    // Every frame shares the size of the largest one so that animations don't
+    // jitter. Pictures are drawn twice rather than held onto, since there can
+    // be a great many of them.
+    let (mut width, mut height, mut background) = (0, 0, None);
+    for canvas in select_frames(pictures, options.frame_stride).map(Picture::draw) {
+        width = width.max(canvas.width * options.scale);
+        height = height.max(canvas.height * options.scale);
+
+        if background.is_none() {
+            background = canvas.get(0, 0);
+        }
+    }
+
+    let background = background.ok_or_else(|| anyhow!("There is nothing to render"))?;
+
+    let canvases = select_frames(pictures, options.frame_stride).map(|picture| {
+        picture
+            .draw()
+            .resized(width, height, options.scale, background)
+    });
+
+    let is_gif = path
+        .extension()
+        .map(|extension| extension.eq_ignore_ascii_case("gif"))
+        .unwrap_or(false);
+
+    if is_gif {
+        write_gif(canvases, (width, height), path, options.frame_delay)
+    } else {
+        write_pngs(canvases, path)
+    }
+}
+
+/// Returns every `frame_stride`th picture in `pictures`, always ending on the
+/// last one.
+fn select_frames<P>(pictures: &[P], frame_stride: usize) -> impl Iterator<Item = &P> {
+    let last_index = pictures.len().saturating_sub(1);
+
+    pictures
+        .iter()
+        .enumerate()
+        .filter(move |(i, _)| i % frame_stride == 0 || *i == last_index)
+        .map(|(_, picture)| picture)
+}
+
+/// Returns the palette of colors in `canvas`, in the order they first appear,
+/// along with each of its pixels' index into that palette.
+fn index_colors(canvas: &Canvas) -> Result<(Vec<Color>, Vec<u8>)> {
+    let mut palette = Vec::<Color>::new();
+    let mut palette_indexes = HashMap::<Color, u8>::new();
+    let mut indexed_pixels = Vec::with_capacity(canvas.pixels.len());
+
+    for pixel in &canvas.pixels {
+        let palette_index = match palette_indexes.get(pixel) {
+            Some(palette_index) => *palette_index,
+            None => {
+                let palette_index = u8::try_from(palette.len())
+                    .map_err(|_| anyhow!("GIF frames cannot have more than 256 colors"))?;
+
+                palette.push(*pixel);
+                palette_indexes.insert(*pixel, palette_index);
+
+                palette_index
+            }
+        };
+
+        indexed_pixels.push(palette_index);
+    }
+
+    Ok((palette, indexed_pixels))
+}
+
+/// Writes `canvases` as a looping animated GIF to the file at `path`.
+///
+/// * `size` is the width and height shared by every one of `canvases`
+/// * `frame_delay` is how many hundredths of a second each frame is shown
+fn write_gif(
+    canvases: impl Iterator<Item = Canvas>,
+    size: (usize, usize),
+    path: &Path,
+    frame_delay: u16,
+) -> Result<()> {
+    let too_large = || anyhow!("{}x{} is too large for a GIF", size.0, size.1);
+    let width = u16::try_from(size.0).map_err(|_| too_large())?;
+    let height = u16::try_from(size.1).map_err(|_| too_large())?;
+
+    let file = File::create(path)
+        .with_context(|| format!("Failed to create file at path \"{}\"", path.display()))?;
+
+    let mut encoder = gif::Encoder::new(BufWriter::new(file), width, height, &[])?;
+    encoder.set_repeat(gif::Repeat::Infinite)?;
+
+    for canvas in canvases {
+        let (palette, indexed_pixels) = index_colors(&canvas)?;
+
+        let frame = gif::Frame {
+            buffer: Cow::Owned(indexed_pixels),
+            delay: frame_delay,
+            height,
+            palette: Some(palette.concat()),
+            width,
+            ..gif::Frame::default()
+        };
+
+        encoder.write_frame(&frame)?;
+    }
+
+    Ok(())
+}
+
+/// Writes each of `canvases` to a numbered PNG file in the directory at
+/// `path`.
+fn write_pngs(canvases: impl Iterator<Item = Canvas>, path: &Path) -> Result<()> {
+    create_dir_all(path)
+        .with_context(|| format!("Failed to create directory at path \"{}\"", path.display()))?;
+
+    for (i, canvas) in canvases.enumerate() {
+        let png_path = path.join(format!("frame_{:05}.png", i));
+
+        let file = File::create(&png_path)
+            .with_context(|| format!("Failed to create file at path \"{}\"", png_path.display()))?;
+
+        let mut encoder = png::Encoder::new(
+            BufWriter::new(file),
+            canvas.width as u32,
+            canvas.height as u32,
+        );
+        encoder.set_color(png::ColorType::Rgb);
+        encoder.set_depth(png::BitDepth::Eight);
+
+        encoder
+            .write_header()?
+            .write_image_data(&canvas.pixels.concat())?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLACK: Color = [0, 0, 0];
+    const WHITE: Color = [255, 255, 255];
+
+    #[test]
+    fn index_colors_orders_the_palette_by_first_appearance() {
+        let mut canvas = Canvas::new(3, 1, WHITE);
+        canvas.set(1, 0, BLACK);
+
+        let (palette, indexed_pixels) = index_colors(&canvas).unwrap();
+
+        assert_eq!(palette, vec![WHITE, BLACK]);
+        assert_eq!(indexed_pixels, vec![0, 1, 0]);
+    }
+
+    #[test]
+    fn resized_scales_and_pads() {
+        let mut canvas = Canvas::new(1, 1, BLACK);
+        canvas.set(0, 0, WHITE);
+
+        let resized = canvas.resized(3, 2, 2, BLACK);
+
+        assert_eq!(
+            resized.pixels,
+            vec![WHITE, WHITE, BLACK, WHITE, WHITE, BLACK]
+        );
+    }
+
+    #[test]
+    fn select_frames_always_includes_the_last_one() {
+        let frames = [0, 1, 2, 3, 4];
+
+        let selected = select_frames(&frames, 3).copied().collect::<Vec<i32>>();
+
+        assert_eq!(selected, vec![0, 3, 4]);
+    }
+
+    #[test]
+    fn set_ignores_pixels_out_of_bounds() {
+        let mut canvas = Canvas::new(1, 1, BLACK);
+
+        canvas.set(1, 0, WHITE);
+        canvas.set(0, 1, WHITE);
+
+        assert_eq!(canvas.pixels, vec![BLACK]);
+    }
+}
@@ -0,0 +1,9 @@
+use anyhow::Result;
+
+/// Puzzle-solving logic for an advent day, kept separate from the program
+/// that reads its input so that it can be exercised directly.
+pub trait Solution {
+    /// Returns the answer to the specified `part` of this advent day given its
+    /// puzzle input, `data`.
+    fn solve(&self, part: u8, data: &str) -> Result<String>;
+}
@@ -0,0 +1,179 @@
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::io::{Error, ErrorKind, Result};
+use std::path::Path;
+
+#[cfg(feature = "generators")]
+use crate::generators::GeneratedInput;
+use crate::solution::Solution;
+
+/// Generates a `#[test]` for each sample of an advent day that checks the
+/// answer produced by the specified [Solution](crate::Solution) against the
+/// expected answer recorded alongside it in `files/`.
+///
+/// The tests are the ones [generate_sample_tests] finds, so the day's build
+/// script has to call it.
+#[macro_export]
+macro_rules! sample_tests {
+    ($solution:expr) => {
+        #[cfg(test)]
+        mod sample_tests {
+            use super::*;
+
+            /// Asserts that the solution produces `expected_answer` for the
+            /// specified `part` of the sample `data`.
+            fn assert_sample_answer(part: u8, data: &str, expected_answer: &str) {
+                $crate::testing::assert_sample_answer(&$solution, part, data, expected_answer);
+            }
+
+            include!(concat!(env!("OUT_DIR"), "/sample_tests.rs"));
+        }
+    };
+}
+
+/// Generates a property test for each part of an advent day that checks the
+/// answers produced by the specified [Solution](crate::Solution) for inputs
+/// drawn from `strategy` against the reference answers generated with them.
+///
+/// Requires the `generators` feature of the `advent` crate in tests.
+#[macro_export]
+macro_rules! reference_tests {
+    ($solution:expr, $strategy:expr) => {
+        #[cfg(test)]
+        mod reference_tests {
+            use super::*;
+
+            $crate::proptest::proptest! {
+                #[test]
+                fn part_1_matches_reference_answer(input in $strategy) {
+                    $crate::testing::assert_reference_answer(&$solution, &input, 1);
+                }
+
+                #[test]
+                fn part_2_matches_reference_answer(input in $strategy) {
+                    $crate::testing::assert_reference_answer(&$solution, &input, 2);
+                }
+            }
+        }
+    };
+}
+
+/// Asserts that `solution` produces `expected_answer` for the specified `part`
+/// of the sample `data`.
+pub fn assert_sample_answer<S: Solution>(
+    solution: &S,
+    part: u8,
+    data: &str,
+    expected_answer: &str,
+) {
+    let answer = solution
+        .solve(part, data)
+        .unwrap_or_else(|error| panic!("Failed to solve part {}: {:?}", part, error));
+
+    assert_eq!(
+        answer.trim_end(),
+        expected_answer.trim_end(),
+        "Part {} produced the wrong answer for the sample data",
+        part
+    );
+}
+
+/// Asserts that `solution` produces the reference answer for the specified
+/// `part` of the generated `input`, or fails if there is no such answer.
+#[cfg(feature = "generators")]
+pub fn assert_reference_answer<S: Solution, I: GeneratedInput>(solution: &S, input: &I, part: u8) {
+    let data = input.encode();
+
+    match input.answer(part) {
+        Some(expected_answer) => {
+            let answer = solution.solve(part, &data).unwrap_or_else(|error| {
+                panic!("Failed to solve part {} of {:?}: {:?}", part, data, error)
+            });
+
+            assert_eq!(
+                answer.trim_end(),
+                expected_answer.trim_end(),
+                "Part {} produced the wrong answer for {:?}",
+                part,
+                data
+            );
+        }
+        None => assert!(
+            solution.solve(part, &data).is_err(),
+            "Part {} produced an answer for {:?}, which should have none",
+            part,
+            data
+        ),
+    }
+}
+
+/// Writes a `#[test]` for each part of each sample in the `files/` directory
+/// of the crate being built to `sample_tests.rs` in `OUT_DIR`, for
+/// [sample_tests] to include. Meant to be called from a day's build script.
+///
+/// Samples named like `sample.txt` or `sample_b.txt` are checked for both
+/// parts, and samples named like `sample_1.txt` or `sample_b_2.txt` only for
+/// the part they end in. The expected answer to part N of either is read from
+/// `sample_N.answer.txt` or `sample_b_N.answer.txt` at build time, so a
+/// missing one fails the build instead of skipping the test.
+pub fn generate_sample_tests() -> Result<()> {
+    let manifest_dir =
+        env::var("CARGO_MANIFEST_DIR").map_err(|error| Error::new(ErrorKind::NotFound, error))?;
+    let out_dir = env::var("OUT_DIR").map_err(|error| Error::new(ErrorKind::NotFound, error))?;
+
+    let files_path = Path::new(&manifest_dir).join("files");
+    println!("cargo:rerun-if-changed={}", files_path.display());
+
+    let mut sample_stems = fs::read_dir(&files_path)?
+        .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+        .collect::<Result<Vec<String>>>()?
+        .into_iter()
+        .filter(|name| name.starts_with("sample") && !name.ends_with(".answer.txt"))
+        .filter_map(|name| name.strip_suffix(".txt").map(str::to_owned))
+        .collect::<Vec<String>>();
+    sample_stems.sort();
+
+    let mut sample_tests = String::new();
+    for sample_stem in &sample_stems {
+        for (answer_stem, part) in sample_parts(sample_stem) {
+            let test_name = format!("part_{}_{}", part, sample_stem)
+                .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
+
+            writeln!(
+                sample_tests,
+                "#[test]\nfn {}() {{\n    assert_sample_answer({}, include_str!({:?}), include_str!({:?}));\n}}",
+                test_name,
+                part,
+                files_path.join(format!("{}.txt", sample_stem)),
+                files_path.join(format!("{}_{}.answer.txt", answer_stem, part))
+            )
+            .unwrap();
+        }
+    }
+
+    fs::write(Path::new(&out_dir).join("sample_tests.rs"), sample_tests)
+}
+
+/// Returns each part that the sample named `sample_stem` is checked for, with
+/// the stem its expected answer files share.
+fn sample_parts(sample_stem: &str) -> Vec<(&str, u8)> {
+    match sample_stem.rsplit_once('_') {
+        Some((answer_stem, "1")) => vec![(answer_stem, 1)],
+        Some((answer_stem, "2")) => vec![(answer_stem, 2)],
+        _ => vec![(sample_stem, 1), (sample_stem, 2)],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_parts_follow_the_part_suffix() {
+        assert_eq!(sample_parts("sample"), [("sample", 1), ("sample", 2)]);
+        assert_eq!(sample_parts("sample_2"), [("sample", 2)]);
+        assert_eq!(sample_parts("sample_b"), [("sample_b", 1), ("sample_b", 2)]);
+        assert_eq!(sample_parts("sample_b_1"), [("sample_b", 1)]);
+    }
+}
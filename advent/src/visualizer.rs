@@ -0,0 +1,288 @@
+use std::io::{stdout, Stdout, Write};
+use std::time::{Duration, Instant};
+
+use anyhow::Result;
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+
+/// One step of a simulation, able to render itself as text.
+pub trait Frame {
+    /// Returns the lines of text depicting this [Frame].
+    ///
+    /// * `width` and `height` describe how much room there is to render in;
+    ///   anything beyond it is cropped
+    fn render(&self, width: usize, height: usize) -> Vec<String>;
+}
+
+/// Plays the specified `frames` in an interactive terminal visualizer until
+/// the user quits.
+pub fn visualize<F: Frame>(frames: &[F]) -> Result<()> {
+    let mut stdout = stdout();
+
+    let _terminal_guard = TerminalGuard::enter(&mut stdout)?;
+
+    play(&mut stdout, frames)
+}
+
+/// Keeps the terminal in raw mode on the alternate screen, restoring it when
+/// dropped so that errors and panics do not leave it unusable.
+struct TerminalGuard;
+
+impl TerminalGuard {
+    /// Switches the terminal behind `stdout` into raw mode on the alternate
+    /// screen, returning a [TerminalGuard] that switches it back.
+    fn enter(stdout: &mut Stdout) -> Result<TerminalGuard> {
+        terminal::enable_raw_mode()?;
+
+        // From here on, dropping the guard undoes whatever was done.
+        let terminal_guard = TerminalGuard;
+        execute!(stdout, EnterAlternateScreen, Hide)?;
+
+        Ok(terminal_guard)
+    }
+}
+
+impl Drop for TerminalGuard {
+    fn drop(&mut self) {
+        // Errors cannot be returned from here, and there is nothing better to
+        // do about them than carry on restoring the rest.
+        let _ = execute!(stdout(), Show, LeaveAlternateScreen);
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+/// Runs the visualizer's event loop, drawing `frames` to `stdout`.
+fn play<F: Frame>(stdout: &mut Stdout, frames: &[F]) -> Result<()> {
+    let mut playback = Playback::new(frames.len());
+    let mut last_advanced_at = Instant::now();
+
+    loop {
+        draw(stdout, frames, &playback)?;
+
+        let timeout = if playback.is_playing {
+            playback
+                .frame_duration()
+                .saturating_sub(last_advanced_at.elapsed())
+        } else {
+            Duration::from_secs(60)
+        };
+
+        if event::poll(timeout)? {
+            if let Event::Key(KeyEvent {
+                code,
+                kind: KeyEventKind::Press,
+                ..
+            }) = event::read()?
+            {
+                match PlaybackControl::from_key_code(code) {
+                    Some(PlaybackControl::Quit) => return Ok(()),
+                    Some(control) => playback.apply(control),
+                    None => {}
+                }
+            }
+        } else if playback.is_playing {
+            playback.advance();
+
+            last_advanced_at = Instant::now();
+        }
+    }
+}
+
+/// Draws the current frame of `playback` and a status line to `stdout`.
+fn draw<F: Frame>(stdout: &mut Stdout, frames: &[F], playback: &Playback) -> Result<()> {
+    let (columns, rows) = terminal::size()?;
+    let (width, height) = (columns as usize, (rows as usize).saturating_sub(2));
+
+    queue!(stdout, Clear(ClearType::All))?;
+
+    if let Some(frame) = frames.get(playback.frame_index) {
+        for (row, line) in frame.render(width, height).iter().take(height).enumerate() {
+            let cropped_line = line.chars().take(width).collect::<String>();
+
+            queue!(stdout, MoveTo(0, row as u16), Print(cropped_line))?;
+        }
+    }
+
+    let status = format!(
+        "{} {}/{} @ {} fps | space: play/pause, \u{2190}/\u{2192}: step, +/-: speed, q: quit",
+        if playback.is_playing {
+            "\u{25b6}"
+        } else {
+            "\u{23f8}"
+        },
+        playback.frame_index + 1,
+        playback.frame_count,
+        playback.frames_per_second,
+    );
+
+    queue!(
+        stdout,
+        MoveTo(0, rows.saturating_sub(1)),
+        Print(status.chars().take(width).collect::<String>())
+    )?;
+
+    stdout.flush()?;
+
+    Ok(())
+}
+
+/// Enumerates every way the user can control playback.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum PlaybackControl {
+    /// Plays frames more quickly.
+    Faster,
+    /// Stops the visualizer.
+    Quit,
+    /// Plays frames more slowly.
+    Slower,
+    /// Pauses on the previous frame.
+    StepBackward,
+    /// Pauses on the next frame.
+    StepForward,
+    /// Plays if paused, or pauses if playing.
+    TogglePlaying,
+}
+
+impl PlaybackControl {
+    /// Returns the [PlaybackControl] bound to the key identified by `code`, if
+    /// there is one.
+    fn from_key_code(code: KeyCode) -> Option<PlaybackControl> {
+        match code {
+            KeyCode::Char(' ') | KeyCode::Char('p') => Some(PlaybackControl::TogglePlaying),
+            KeyCode::Right | KeyCode::Char('l') => Some(PlaybackControl::StepForward),
+            KeyCode::Left | KeyCode::Char('h') => Some(PlaybackControl::StepBackward),
+            KeyCode::Up | KeyCode::Char('+') | KeyCode::Char('=') => Some(PlaybackControl::Faster),
+            KeyCode::Down | KeyCode::Char('-') => Some(PlaybackControl::Slower),
+            KeyCode::Esc | KeyCode::Char('q') => Some(PlaybackControl::Quit),
+            _ => None,
+        }
+    }
+}
+
+/// Playback state of the visualizer.
+#[derive(Debug, Eq, PartialEq)]
+struct Playback {
+    /// How many frames there are to play.
+    frame_count: usize,
+    /// Index of the frame currently on screen.
+    frame_index: usize,
+    /// How many frames are shown each second while playing.
+    frames_per_second: u32,
+    /// `true` if frames are advancing on their own.
+    is_playing: bool,
+}
+
+impl Playback {
+    /// Returns a paused [Playback] at the first of `frame_count` frames.
+    fn new(frame_count: usize) -> Playback {
+        Playback {
+            frame_count,
+            frame_index: 0,
+            frames_per_second: DEFAULT_FRAMES_PER_SECOND,
+            is_playing: false,
+        }
+    }
+
+    /// Moves on to the next frame while playing, pausing on the last one.
+    fn advance(&mut self) {
+        self.frame_index = (self.frame_index + 1).min(self.frame_count.saturating_sub(1));
+        self.is_playing = self.frame_index + 1 < self.frame_count;
+    }
+
+    /// Updates this [Playback] according to `control`.
+    fn apply(&mut self, control: PlaybackControl) {
+        match control {
+            PlaybackControl::Faster => {
+                self.frames_per_second = (self.frames_per_second * 2).min(MAX_FRAMES_PER_SECOND);
+            }
+            PlaybackControl::Quit => {}
+            PlaybackControl::Slower => {
+                self.frames_per_second = (self.frames_per_second / 2).max(1);
+            }
+            PlaybackControl::StepBackward => {
+                self.is_playing = false;
+                self.frame_index = self.frame_index.saturating_sub(1);
+            }
+            PlaybackControl::StepForward => {
+                self.is_playing = false;
+                self.frame_index = (self.frame_index + 1).min(self.frame_count.saturating_sub(1));
+            }
+            PlaybackControl::TogglePlaying => {
+                if !self.is_playing && self.frame_index + 1 >= self.frame_count {
+                    self.frame_index = 0;
+                }
+
+                self.is_playing = !self.is_playing;
+            }
+        }
+    }
+
+    /// Returns how long each frame stays on screen while playing.
+    fn frame_duration(&self) -> Duration {
+        Duration::from_secs(1) / self.frames_per_second
+    }
+}
+
+/// How many frames are shown each second when the visualizer starts.
+const DEFAULT_FRAMES_PER_SECOND: u32 = 8;
+
+/// Upper bound for how many frames can be shown each second.
+const MAX_FRAMES_PER_SECOND: u32 = 512;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stepping_stays_within_bounds_and_pauses() {
+        let mut playback = Playback::new(2);
+        playback.is_playing = true;
+
+        playback.apply(PlaybackControl::StepBackward);
+        assert_eq!(playback.frame_index, 0);
+        assert!(!playback.is_playing);
+
+        playback.apply(PlaybackControl::StepForward);
+        playback.apply(PlaybackControl::StepForward);
+        assert_eq!(playback.frame_index, 1);
+    }
+
+    #[test]
+    fn advancing_pauses_on_the_last_frame() {
+        let mut playback = Playback::new(2);
+        playback.is_playing = true;
+
+        playback.advance();
+        assert_eq!(playback.frame_index, 1);
+        assert!(!playback.is_playing);
+    }
+
+    #[test]
+    fn playing_from_the_last_frame_restarts() {
+        let mut playback = Playback::new(3);
+        playback.frame_index = 2;
+
+        playback.apply(PlaybackControl::TogglePlaying);
+
+        assert_eq!(playback.frame_index, 0);
+        assert!(playback.is_playing);
+    }
+
+    #[test]
+    fn speed_is_clamped() {
+        let mut playback = Playback::new(1);
+
+        for _ in 0..20 {
+            playback.apply(PlaybackControl::Faster);
+        }
+        assert_eq!(playback.frames_per_second, MAX_FRAMES_PER_SECOND);
+
+        for _ in 0..20 {
+            playback.apply(PlaybackControl::Slower);
+        }
+        assert_eq!(playback.frames_per_second, 1);
+    }
+}
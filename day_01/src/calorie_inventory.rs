@@ -0,0 +1,378 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::io::{BufRead, Lines};
+use std::vec::IntoIter;
+
+use anyhow::{Context, Result};
+
+use crate::inventory_separator::InventorySeparator;
+
+/// Calorie counts of the food items carried by a single elf.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct ElfInventory {
+    /// Calorie count of each item this elf carries.
+    pub(crate) calorie_counts: Vec<u64>,
+    /// Position of this elf in the inventory, starting from 1.
+    pub(crate) elf_number: usize,
+}
+
+impl ElfInventory {
+    /// Returns the total calories carried by this elf.
+    pub(crate) fn calorie_total(&self) -> ElfCalorieTotal {
+        ElfCalorieTotal {
+            calorie_total: self
+                .calorie_counts
+                .iter()
+                .map(|&calorie_count| u128::from(calorie_count))
+                .sum(),
+            elf_number: self.elf_number,
+        }
+    }
+}
+
+/// Total calories carried by a single elf.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub(crate) struct ElfCalorieTotal {
+    /// Sum of the calorie counts of every item the elf carries.
+    pub(crate) calorie_total: u128,
+    /// Position of the elf in the inventory, starting from 1.
+    pub(crate) elf_number: usize,
+}
+
+/// Iterator that reads one [ElfInventory] at a time from a reader.
+///
+/// Runs of separators count as one, and separators before the first elf or
+/// after the last are ignored. Unless elves are separated by column,
+/// inventories never need to be held in memory all at once.
+pub(crate) struct ElfInventories<R: BufRead> {
+    /// Inventories read ahead of time, for separators that require it.
+    buffered_elf_inventories: Option<IntoIter<ElfInventory>>,
+    /// How many elves have been read so far.
+    elf_count: usize,
+    /// `true` once there is nothing left to read.
+    is_exhausted: bool,
+    /// How many lines have been read so far.
+    line_number: usize,
+    /// Lines of the underlying reader.
+    lines: Lines<R>,
+    /// How elves' inventories are told apart.
+    separator: InventorySeparator,
+}
+
+impl<R: BufRead> ElfInventories<R> {
+    /// Creates and returns a new [ElfInventories] reading from `reader`.
+    ///
+    /// * `separator` decides how elves' inventories are told apart
+    pub(crate) fn new(reader: R, separator: InventorySeparator) -> ElfInventories<R> {
+        ElfInventories {
+            buffered_elf_inventories: None,
+            elf_count: 0,
+            is_exhausted: false,
+            line_number: 0,
+            lines: reader.lines(),
+            separator,
+        }
+    }
+
+    /// Returns the next line of the underlying reader, if there is one.
+    fn next_line(&mut self) -> Option<Result<String>> {
+        self.line_number += 1;
+
+        self.lines
+            .next()
+            .map(|line| line.map_err(anyhow::Error::from))
+    }
+
+    /// Returns the next [ElfInventory] when items are listed one per line.
+    fn next_listed(&mut self) -> Option<Result<ElfInventory>> {
+        let elf_number = self.elf_count + 1;
+
+        let mut calorie_counts = vec![];
+        while let Some(elven_inventory_line) = self.next_line() {
+            let elven_inventory_line = match elven_inventory_line {
+                Ok(elven_inventory_line) => elven_inventory_line,
+                Err(error) => return Some(Err(error)),
+            };
+
+            if self.separator.is_separator(&elven_inventory_line) {
+                if calorie_counts.is_empty() {
+                    continue;
+                }
+
+                break;
+            }
+
+            match parse_calorie_count(
+                &elven_inventory_line,
+                elf_number,
+                calorie_counts.len() + 1,
+                self.line_number,
+            ) {
+                Ok(calorie_count) => calorie_counts.push(calorie_count),
+                Err(error) => return Some(Err(error)),
+            }
+        }
+
+        if calorie_counts.is_empty() {
+            return None;
+        }
+
+        self.elf_count = elf_number;
+
+        Some(Ok(ElfInventory {
+            calorie_counts,
+            elf_number,
+        }))
+    }
+
+    /// Returns every [ElfInventory] when each elf's items fill a column.
+    fn read_columns(&mut self) -> Result<Vec<ElfInventory>> {
+        let mut calorie_counts_by_elf = Vec::<Vec<u64>>::new();
+
+        while let Some(elven_inventory_line) = self.next_line() {
+            let elven_inventory_line = elven_inventory_line?;
+            if elven_inventory_line.trim().is_empty() {
+                continue;
+            }
+
+            for (column_index, cell) in elven_inventory_line.split(',').enumerate() {
+                if calorie_counts_by_elf.len() <= column_index {
+                    calorie_counts_by_elf.resize_with(column_index + 1, Vec::new);
+                }
+
+                if !cell.trim().is_empty() {
+                    let item_number = calorie_counts_by_elf[column_index].len() + 1;
+
+                    calorie_counts_by_elf[column_index].push(parse_calorie_count(
+                        cell,
+                        column_index + 1,
+                        item_number,
+                        self.line_number,
+                    )?);
+                }
+            }
+        }
+
+        Ok(calorie_counts_by_elf
+            .into_iter()
+            .enumerate()
+            .map(|(column_index, calorie_counts)| ElfInventory {
+                calorie_counts,
+                elf_number: column_index + 1,
+            })
+            .collect())
+    }
+}
+
+impl<R: BufRead> Iterator for ElfInventories<R> {
+    type Item = Result<ElfInventory>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.is_exhausted {
+            return None;
+        }
+
+        let elf_inventory = match self.separator {
+            InventorySeparator::Column => {
+                if self.buffered_elf_inventories.is_none() {
+                    match self.read_columns() {
+                        Ok(elf_inventories) => {
+                            self.buffered_elf_inventories = Some(elf_inventories.into_iter())
+                        }
+                        Err(error) => {
+                            self.is_exhausted = true;
+
+                            return Some(Err(error));
+                        }
+                    }
+                }
+
+                self.buffered_elf_inventories
+                    .as_mut()
+                    .and_then(|elf_inventories| elf_inventories.next())
+                    .map(Ok)
+            }
+            _ => self.next_listed(),
+        };
+
+        // Nothing more is read after the end of the input or an error.
+        if !matches!(elf_inventory, Some(Ok(_))) {
+            self.is_exhausted = true;
+        }
+
+        elf_inventory
+    }
+}
+
+/// Returns the calorie count listed in `cell`.
+///
+/// * `elf_number`, `item_number`, and `line_number` locate `cell`, for error
+///   messages
+pub(crate) fn parse_calorie_count(
+    cell: &str,
+    elf_number: usize,
+    item_number: usize,
+    line_number: usize,
+) -> Result<u64> {
+    cell.trim().parse::<u64>().with_context(|| {
+        format!(
+            "Elf {}'s item {} (line {}) is not a calorie count: {:?}",
+            elf_number, item_number, line_number, cell
+        )
+    })
+}
+
+/// Returns an iterator over the total calories carried by each elf in
+/// `elf_inventories`.
+pub(crate) fn calorie_totals(
+    elf_inventories: impl Iterator<Item = Result<ElfInventory>>,
+) -> impl Iterator<Item = Result<ElfCalorieTotal>> {
+    elf_inventories
+        .map(|elf_inventory| elf_inventory.map(|elf_inventory| elf_inventory.calorie_total()))
+}
+
+/// Returns the `count` largest of the specified `elf_calorie_totals`,
+/// largest first, without holding on to more than `count` of them at a time.
+///
+/// Elves with equal totals are ranked in inventory order.
+pub(crate) fn top_calorie_totals(
+    elf_calorie_totals: impl Iterator<Item = Result<ElfCalorieTotal>>,
+    count: usize,
+) -> Result<Vec<ElfCalorieTotal>> {
+    // The heap is ordered so that the weakest contender is always on top,
+    // ready to be evicted.
+    let mut top_calorie_totals = BinaryHeap::with_capacity(count + 1);
+
+    for elf_calorie_total in elf_calorie_totals {
+        let ElfCalorieTotal {
+            calorie_total,
+            elf_number,
+        } = elf_calorie_total?;
+
+        top_calorie_totals.push(Reverse((calorie_total, Reverse(elf_number))));
+
+        if top_calorie_totals.len() > count {
+            top_calorie_totals.pop();
+        }
+    }
+
+    Ok(top_calorie_totals
+        .into_sorted_vec()
+        .into_iter()
+        .map(
+            |Reverse((calorie_total, Reverse(elf_number)))| ElfCalorieTotal {
+                calorie_total,
+                elf_number,
+            },
+        )
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn elf_inventories_are_numbered_in_order() {
+        let elf_inventories =
+            ElfInventories::new("1\n2\n\n3".as_bytes(), InventorySeparator::BlankLine)
+                .collect::<Result<Vec<ElfInventory>>>()
+                .unwrap();
+
+        assert_eq!(
+            elf_inventories,
+            vec![
+                ElfInventory {
+                    calorie_counts: vec![1, 2],
+                    elf_number: 1
+                },
+                ElfInventory {
+                    calorie_counts: vec![3],
+                    elf_number: 2
+                },
+            ]
+        );
+    }
+
+    /// Returns the calorie counts of each elf listed in `elven_inventory_data`.
+    fn read(elven_inventory_data: &str, separator: &str) -> Result<Vec<Vec<u64>>> {
+        ElfInventories::new(elven_inventory_data.as_bytes(), separator.parse().unwrap())
+            .map(|elf_inventory| elf_inventory.map(|elf_inventory| elf_inventory.calorie_counts))
+            .collect()
+    }
+
+    #[test]
+    fn whitespace_only_lines_separate_elves() {
+        assert_eq!(
+            read("\n1\n2\n  \n\t\n3\n \n", "blank").unwrap(),
+            vec![vec![1, 2], vec![3]]
+        );
+    }
+
+    #[test]
+    fn line_separators_work() {
+        assert_eq!(
+            read("1\n2\n---\n3\n", "---").unwrap(),
+            vec![vec![1, 2], vec![3]]
+        );
+    }
+
+    #[test]
+    fn column_separators_work() {
+        assert_eq!(
+            read("1,3,5\n2,,6\n,4\n", "column").unwrap(),
+            vec![vec![1, 2], vec![3, 4], vec![5, 6]]
+        );
+    }
+
+    #[test]
+    fn errors_name_the_elf_of_malformed_lines() {
+        let error = read("1\n\n2\nthree\n", "blank").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Elf 2's item 2 (line 4) is not a calorie count: \"three\""
+        );
+
+        let error = read("1,2\n3,x\n", "column").unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Elf 2's item 2 (line 2) is not a calorie count: \"x\""
+        );
+    }
+
+    #[test]
+    fn calorie_totals_do_not_overflow() {
+        let elf_inventory = ElfInventories::new(
+            "18446744073709551615\n18446744073709551615".as_bytes(),
+            InventorySeparator::BlankLine,
+        )
+        .next()
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(
+            elf_inventory.calorie_total().calorie_total,
+            2 * u128::from(u64::MAX)
+        );
+    }
+
+    #[test]
+    fn top_calorie_totals_ranks_ties_in_inventory_order() {
+        let elf_calorie_totals =
+            [(5, 1), (9, 2), (5, 3), (7, 4)].map(|(calorie_total, elf_number)| {
+                Ok(ElfCalorieTotal {
+                    calorie_total,
+                    elf_number,
+                })
+            });
+
+        let top = top_calorie_totals(elf_calorie_totals.into_iter(), 3).unwrap();
+
+        assert_eq!(
+            top.iter()
+                .map(|elf_calorie_total| elf_calorie_total.elf_number)
+                .collect::<Vec<usize>>(),
+            vec![2, 4, 1]
+        );
+    }
+}
@@ -0,0 +1,168 @@
+use std::fmt::Display;
+
+use anyhow::Result;
+
+use crate::calorie_inventory::{top_calorie_totals, ElfCalorieTotal};
+
+/// Summary of how many calories the elves carry.
+#[derive(Debug)]
+pub(crate) struct CalorieStatistics {
+    /// Total calories carried by each elf, in inventory order.
+    elf_calorie_totals: Vec<ElfCalorieTotal>,
+    /// Elves carrying the most calories, largest first.
+    top_calorie_totals: Vec<ElfCalorieTotal>,
+}
+
+impl CalorieStatistics {
+    /// Returns the [CalorieStatistics] of the specified `elf_calorie_totals`.
+    ///
+    /// * `top_count` is how many of the elves carrying the most calories are
+    ///   singled out
+    pub(crate) fn gather(
+        elf_calorie_totals: impl Iterator<Item = Result<ElfCalorieTotal>>,
+        top_count: usize,
+    ) -> Result<CalorieStatistics> {
+        let elf_calorie_totals = elf_calorie_totals.collect::<Result<Vec<ElfCalorieTotal>>>()?;
+
+        let top_calorie_totals =
+            top_calorie_totals(elf_calorie_totals.iter().copied().map(Ok), top_count)?;
+
+        Ok(CalorieStatistics {
+            elf_calorie_totals,
+            top_calorie_totals,
+        })
+    }
+
+    /// Returns the average number of calories carried by an elf, if there are
+    /// any elves.
+    pub(crate) fn mean(&self) -> Option<f64> {
+        if self.elf_calorie_totals.is_empty() {
+            return None;
+        }
+
+        let calorie_total_sum = self
+            .elf_calorie_totals
+            .iter()
+            .map(|elf_calorie_total| elf_calorie_total.calorie_total as f64)
+            .sum::<f64>();
+
+        Some(calorie_total_sum / self.elf_calorie_totals.len() as f64)
+    }
+
+    /// Returns the median number of calories carried by an elf, if there are
+    /// any elves.
+    pub(crate) fn median(&self) -> Option<f64> {
+        let mut calorie_totals = self
+            .elf_calorie_totals
+            .iter()
+            .map(|elf_calorie_total| elf_calorie_total.calorie_total)
+            .collect::<Vec<u128>>();
+
+        calorie_totals.sort_unstable();
+
+        let middle = calorie_totals.len() / 2;
+        match calorie_totals.len() {
+            0 => None,
+            length if length % 2 == 0 => {
+                Some((calorie_totals[middle - 1] as f64 + calorie_totals[middle] as f64) / 2.0)
+            }
+            _ => Some(calorie_totals[middle] as f64),
+        }
+    }
+}
+
+impl Display for CalorieStatistics {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Elves: {}", self.elf_calorie_totals.len())?;
+        if let (Some(mean), Some(median)) = (self.mean(), self.median()) {
+            writeln!(f, "Mean calorie total: {:.1}", mean)?;
+            writeln!(f, "Median calorie total: {:.1}", median)?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Top {} elves:", self.top_calorie_totals.len())?;
+        for (rank, elf_calorie_total) in self.top_calorie_totals.iter().enumerate() {
+            writeln!(
+                f,
+                "{:>4}. elf {}: {}",
+                rank + 1,
+                elf_calorie_total.elf_number,
+                elf_calorie_total.calorie_total
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Calorie totals by elf:")?;
+
+        // Bars are scaled to the largest total of any elf.
+        let max_calorie_total = self
+            .elf_calorie_totals
+            .iter()
+            .map(|elf_calorie_total| elf_calorie_total.calorie_total)
+            .max()
+            .unwrap_or(0)
+            .max(1);
+        let elf_number_width = self.elf_calorie_totals.len().to_string().len();
+
+        for elf_calorie_total in &self.elf_calorie_totals {
+            let bar_length = elf_calorie_total.calorie_total * HISTOGRAM_WIDTH / max_calorie_total;
+
+            writeln!(
+                f,
+                "elf {:>width$} | {} {}",
+                elf_calorie_total.elf_number,
+                "#".repeat(bar_length as usize),
+                elf_calorie_total.calorie_total,
+                width = elf_number_width
+            )?;
+        }
+
+        std::fmt::Result::Ok(())
+    }
+}
+
+/// How many characters wide the longest bar of the histogram is.
+const HISTOGRAM_WIDTH: u128 = 50;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns [CalorieStatistics] for elves carrying `calorie_totals`,
+    /// singling out the `top_count` elves carrying the most.
+    fn gather(calorie_totals: &[u128], top_count: usize) -> CalorieStatistics {
+        let elf_calorie_totals = calorie_totals
+            .iter()
+            .enumerate()
+            .map(|(i, &calorie_total)| {
+                Ok(ElfCalorieTotal {
+                    calorie_total,
+                    elf_number: i + 1,
+                })
+            })
+            .collect::<Vec<Result<ElfCalorieTotal>>>();
+
+        CalorieStatistics::gather(elf_calorie_totals.into_iter(), top_count).unwrap()
+    }
+
+    #[test]
+    fn median_averages_the_middle_pair() {
+        assert_eq!(gather(&[4, 1, 3, 2], 1).median(), Some(2.5));
+        assert_eq!(gather(&[4, 1, 3], 1).median(), Some(3.0));
+        assert_eq!(gather(&[], 1).median(), None);
+    }
+
+    #[test]
+    fn mean_works() {
+        assert_eq!(gather(&[1, 2, 6], 1).mean(), Some(3.0));
+        assert_eq!(gather(&[], 1).mean(), None);
+    }
+
+    #[test]
+    fn histogram_is_scaled_to_the_largest_total_given_no_top_elves() {
+        let report = gather(&[100, 50], 0).to_string();
+
+        assert!(report.contains(&format!("elf 1 | {} 100\n", "#".repeat(50))));
+        assert!(report.contains(&format!("elf 2 | {} 50\n", "#".repeat(25))));
+    }
+}
@@ -0,0 +1,353 @@
+use std::collections::BTreeMap;
+use std::io::Write;
+use std::iter::once;
+
+use anyhow::{anyhow, Context, Result};
+use clap::ValueEnum;
+use serde_json::Value;
+
+use crate::calorie_inventory::{parse_calorie_count, ElfInventories, ElfInventory};
+use crate::inventory_separator::InventorySeparator;
+
+/// Enumerates every format that elves' inventories can be written in.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, ValueEnum)]
+pub(crate) enum InventoryFormat {
+    /// One `elf,item,calories` row per item, optionally beneath a header row
+    /// naming those columns.
+    Csv,
+    /// An array holding an array of calorie counts for each elf.
+    Json,
+    /// Items listed one per line, with elves told apart by a separator.
+    #[default]
+    Text,
+}
+
+impl InventoryFormat {
+    /// Returns an iterator over the inventory of each elf in
+    /// `elven_inventory_data`, written in this [InventoryFormat].
+    ///
+    /// * `separator` decides how elves' inventories are told apart in text
+    pub(crate) fn read<'a>(
+        &self,
+        elven_inventory_data: &'a str,
+        separator: &InventorySeparator,
+    ) -> Box<dyn Iterator<Item = Result<ElfInventory>> + 'a> {
+        let elf_inventories = match self {
+            InventoryFormat::Csv => read_csv(elven_inventory_data),
+            InventoryFormat::Json => read_json(elven_inventory_data),
+            InventoryFormat::Text => {
+                return Box::new(ElfInventories::new(
+                    elven_inventory_data.as_bytes(),
+                    separator.clone(),
+                ))
+            }
+        };
+
+        match elf_inventories {
+            Ok(elf_inventories) => Box::new(elf_inventories.into_iter().map(Ok)),
+            Err(error) => Box::new(once(Err(error))),
+        }
+    }
+
+    /// Writes each of `elf_inventories` to `writer` in this
+    /// [InventoryFormat].
+    ///
+    /// Text is always written with elves separated by blank lines.
+    pub(crate) fn write(
+        &self,
+        elf_inventories: impl Iterator<Item = Result<ElfInventory>>,
+        mut writer: impl Write,
+    ) -> Result<()> {
+        match self {
+            InventoryFormat::Csv => {
+                writeln!(writer, "{}", CSV_HEADER.join(","))?;
+
+                for elf_inventory in elf_inventories {
+                    let elf_inventory = elf_inventory?;
+
+                    for (i, calorie_count) in elf_inventory.calorie_counts.iter().enumerate() {
+                        writeln!(
+                            writer,
+                            "{},{},{}",
+                            elf_inventory.elf_number,
+                            i + 1,
+                            calorie_count
+                        )?;
+                    }
+                }
+            }
+            InventoryFormat::Json => {
+                let calorie_counts_by_elf = elf_inventories
+                    .map(|elf_inventory| {
+                        elf_inventory.map(|elf_inventory| elf_inventory.calorie_counts)
+                    })
+                    .collect::<Result<Vec<Vec<u64>>>>()?;
+
+                serde_json::to_writer_pretty(&mut writer, &calorie_counts_by_elf)?;
+                writeln!(writer)?;
+            }
+            InventoryFormat::Text => {
+                for (i, elf_inventory) in elf_inventories.enumerate() {
+                    if i > 0 {
+                        writeln!(writer)?;
+                    }
+
+                    for calorie_count in elf_inventory?.calorie_counts {
+                        writeln!(writer, "{}", calorie_count)?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the inventory of each elf listed in the CSV `elven_inventory_data`,
+/// ordered by elf and then by item.
+///
+/// Rows may come in any order, but no item can be listed twice, and elves and
+/// each elf's items must be numbered from 1 without gaps.
+fn read_csv(elven_inventory_data: &str) -> Result<Vec<ElfInventory>> {
+    // Each item's calorie count is kept with the line it was listed on.
+    let mut calorie_counts_by_elf = BTreeMap::<usize, BTreeMap<usize, (u64, usize)>>::new();
+
+    for (i, line) in elven_inventory_data.lines().enumerate() {
+        let line_number = i + 1;
+
+        let fields = line
+            .split(',')
+            .map(|field| field.trim().trim_matches('"'))
+            .collect::<Vec<&str>>();
+
+        let is_header = fields.len() == CSV_HEADER.len()
+            && fields
+                .iter()
+                .zip(CSV_HEADER)
+                .all(|(field, column_name)| field.eq_ignore_ascii_case(column_name));
+
+        if line.trim().is_empty() || is_header {
+            continue;
+        }
+
+        let [elf, item, calories] = fields[..] else {
+            return Err(anyhow!(
+                "Line {} should have {} fields ({}) but has {}",
+                line_number,
+                CSV_HEADER.len(),
+                CSV_HEADER.join(","),
+                fields.len()
+            ));
+        };
+
+        let elf_number = elf
+            .parse::<usize>()
+            .with_context(|| format!("Line {} does not name an elf: {:?}", line_number, elf))?;
+        let item_number = item.parse::<usize>().with_context(|| {
+            format!(
+                "Elf {}'s item on line {} does not have an index: {:?}",
+                elf_number, line_number, item
+            )
+        })?;
+        if elf_number == 0 {
+            return Err(anyhow!(
+                "Line {} names elf 0, but elves are numbered from 1",
+                line_number
+            ));
+        }
+        if item_number == 0 {
+            return Err(anyhow!(
+                "Elf {}'s item on line {} is item 0, but items are numbered from 1",
+                elf_number,
+                line_number
+            ));
+        }
+        let calorie_count = parse_calorie_count(calories, elf_number, item_number, line_number)?;
+
+        let calorie_counts = calorie_counts_by_elf.entry(elf_number).or_default();
+        if calorie_counts
+            .insert(item_number, (calorie_count, line_number))
+            .is_some()
+        {
+            return Err(anyhow!(
+                "Elf {}'s item {} (line {}) was already listed",
+                elf_number,
+                item_number,
+                line_number
+            ));
+        }
+    }
+
+    (1..)
+        .zip(calorie_counts_by_elf)
+        .map(|(expected_elf_number, (elf_number, calorie_counts))| {
+            if elf_number != expected_elf_number {
+                let line_number = calorie_counts.values().map(|(_, line)| *line).min();
+
+                return Err(anyhow!(
+                    "Elf {} (line {}) is listed, but elf {} is not",
+                    elf_number,
+                    line_number.unwrap_or_default(),
+                    expected_elf_number
+                ));
+            }
+
+            let calorie_counts = (1..)
+                .zip(calorie_counts)
+                .map(
+                    |(expected_item_number, (item_number, (calorie_count, line)))| {
+                        if item_number != expected_item_number {
+                            return Err(anyhow!(
+                                "Elf {}'s item {} (line {}) is listed, but item {} is not",
+                                elf_number,
+                                item_number,
+                                line,
+                                expected_item_number
+                            ));
+                        }
+
+                        Ok(calorie_count)
+                    },
+                )
+                .collect::<Result<Vec<u64>>>()?;
+
+            Ok(ElfInventory {
+                calorie_counts,
+                elf_number,
+            })
+        })
+        .collect()
+}
+
+/// Returns the inventory of each elf listed in the JSON
+/// `elven_inventory_data`.
+fn read_json(elven_inventory_data: &str) -> Result<Vec<ElfInventory>> {
+    let json = serde_json::from_str::<Value>(elven_inventory_data)
+        .context("Failed to parse elven inventories as JSON")?;
+
+    let elves = json
+        .as_array()
+        .context("Elven inventories should be an array with an entry for each elf")?;
+
+    elves
+        .iter()
+        .enumerate()
+        .map(|(i, items)| {
+            let elf_number = i + 1;
+
+            let items = items.as_array().with_context(|| {
+                format!(
+                    "Elf {} should be an array of calorie counts but is {}",
+                    elf_number, items
+                )
+            })?;
+
+            let calorie_counts = items
+                .iter()
+                .enumerate()
+                .map(|(j, item)| {
+                    item.as_u64().with_context(|| {
+                        format!(
+                            "Elf {}'s item {} is not a calorie count: {}",
+                            elf_number,
+                            j + 1,
+                            item
+                        )
+                    })
+                })
+                .collect::<Result<Vec<u64>>>()?;
+
+            Ok(ElfInventory {
+                calorie_counts,
+                elf_number,
+            })
+        })
+        .collect()
+}
+
+/// Names of the columns of the CSV format, in order.
+const CSV_HEADER: [&str; 3] = ["elf", "item", "calories"];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Text inventory that every format should round-trip.
+    const ELVEN_INVENTORY_DATA: &str = "1000\n2000\n\n4000\n\n5000\n6000\n";
+
+    /// Returns `elven_inventory_data`, converted from the `from` format to the
+    /// `to` format.
+    fn convert(
+        elven_inventory_data: &str,
+        from: InventoryFormat,
+        to: InventoryFormat,
+    ) -> Result<String> {
+        let mut converted = vec![];
+
+        to.write(
+            from.read(elven_inventory_data, &InventorySeparator::BlankLine),
+            &mut converted,
+        )?;
+
+        Ok(String::from_utf8(converted)?)
+    }
+
+    #[test]
+    fn every_format_round_trips() {
+        for format in InventoryFormat::value_variants() {
+            let converted = convert(ELVEN_INVENTORY_DATA, InventoryFormat::Text, *format).unwrap();
+            let restored = convert(&converted, *format, InventoryFormat::Text).unwrap();
+
+            assert_eq!(restored, ELVEN_INVENTORY_DATA, "{:?}", format);
+        }
+    }
+
+    #[test]
+    fn read_csv_orders_rows() {
+        let elf_inventories = read_csv("elf,item,calories\n2,1,30\n1,2,20\n1,1,10\n").unwrap();
+
+        assert_eq!(
+            elf_inventories,
+            vec![
+                ElfInventory {
+                    calorie_counts: vec![10, 20],
+                    elf_number: 1,
+                },
+                ElfInventory {
+                    calorie_counts: vec![30],
+                    elf_number: 2,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn errors_name_the_elf_and_item() {
+        assert_eq!(
+            read_csv("1,1,10\n2,3,ten\n").unwrap_err().to_string(),
+            "Elf 2's item 3 (line 2) is not a calorie count: \"ten\""
+        );
+        assert_eq!(
+            read_csv("1,1,10\n1,1,20\n").unwrap_err().to_string(),
+            "Elf 1's item 1 (line 2) was already listed"
+        );
+        assert_eq!(
+            read_csv("1,1,10\n3,1,30\n3,2,40\n")
+                .unwrap_err()
+                .to_string(),
+            "Elf 3 (line 2) is listed, but elf 2 is not"
+        );
+        assert_eq!(
+            read_csv("1,3,30\n1,1,10\n").unwrap_err().to_string(),
+            "Elf 1's item 3 (line 1) is listed, but item 2 is not"
+        );
+        assert_eq!(
+            read_csv("1,0,10\n").unwrap_err().to_string(),
+            "Elf 1's item on line 1 is item 0, but items are numbered from 1"
+        );
+        assert_eq!(
+            read_json("[[10], [20, -1]]").unwrap_err().to_string(),
+            "Elf 2's item 2 is not a calorie count: -1"
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use std::convert::Infallible;
+use std::str::FromStr;
+
+/// Enumerates every way that elves' inventories can be told apart.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub(crate) enum InventorySeparator {
+    /// Items are listed one per line, and elves are separated by lines that
+    /// contain nothing but whitespace.
+    #[default]
+    BlankLine,
+    /// Each line is a comma-separated row holding one item for every elf, so
+    /// that each elf's items fill a column; empty cells are skipped.
+    Column,
+    /// Items are listed one per line, and elves are separated by lines that
+    /// contain nothing but the specified text, give or take whitespace.
+    Line(String),
+}
+
+impl InventorySeparator {
+    /// Returns `true` if `line` separates one elf's items from the next.
+    ///
+    /// This is never the case for [InventorySeparator::Column], where elves
+    /// are told apart by their position within each line instead.
+    pub(crate) fn is_separator(&self, line: &str) -> bool {
+        match self {
+            InventorySeparator::BlankLine => line.trim().is_empty(),
+            InventorySeparator::Column => false,
+            InventorySeparator::Line(separator) => {
+                line.trim().is_empty() || line.trim() == separator.trim()
+            }
+        }
+    }
+}
+
+impl FromStr for InventorySeparator {
+    type Err = Infallible;
+
+    /// Returns the [InventorySeparator] named by `s`: `blank` and `column`
+    /// name their namesakes, and anything else is treated as the text of
+    /// separator lines.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s {
+            "blank" => InventorySeparator::BlankLine,
+            "column" => InventorySeparator::Column,
+            _ => InventorySeparator::Line(s.to_owned()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn whitespace_only_lines_are_separators() {
+        for separator in [
+            InventorySeparator::BlankLine,
+            InventorySeparator::Line("---".to_owned()),
+        ] {
+            assert!(separator.is_separator(""));
+            assert!(separator.is_separator(" \t "));
+            assert!(!separator.is_separator(" 100 "));
+        }
+    }
+
+    #[test]
+    fn line_separators_ignore_surrounding_whitespace() {
+        let separator = "---".parse::<InventorySeparator>().unwrap();
+
+        assert!(separator.is_separator(" --- "));
+        assert!(!separator.is_separator("--"));
+    }
+}
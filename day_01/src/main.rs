@@ -1,96 +1,151 @@
 extern crate advent;
 extern crate anyhow;
-extern crate lazy_static;
-extern crate regex;
+extern crate clap;
 extern crate tokio;
 
-use anyhow::{Context, Result};
-use lazy_static::lazy_static;
-use regex::Regex;
+mod calorie_inventory;
+mod calorie_statistics;
+mod inventory_format;
+mod inventory_separator;
+
+use advent::{Config, Solution};
+use anyhow::{anyhow, Result};
+use calorie_inventory::{calorie_totals, top_calorie_totals};
+use calorie_statistics::CalorieStatistics;
+use clap::Parser;
+use inventory_format::InventoryFormat;
+use inventory_separator::InventorySeparator;
+use std::io::stdout;
+
+/// Command line arguments for the "Calorie Counting" puzzle.
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(flatten)]
+    config: Config,
+
+    /// Lists this many of the elves carrying the most calories, and their sum,
+    /// instead of printing the answer.
+    #[arg(long)]
+    top: Option<usize>,
+
+    /// Prints statistics about the calories every elf carries instead of
+    /// printing the answer.
+    #[arg(long)]
+    stats: bool,
+
+    /// Specifies how elves' inventories are told apart: `blank` for lines of
+    /// whitespace, `column` for one comma-separated column per elf, or any
+    /// other text for lines consisting of that text.
+    #[arg(default_value = "blank", long)]
+    separator: InventorySeparator,
+
+    /// Specifies which format the elves' inventories are written in.
+    #[arg(default_value_t = InventoryFormat::Text, long, value_enum)]
+    format: InventoryFormat,
+
+    /// Prints the elves' inventories in the specified format instead of
+    /// printing the answer.
+    #[arg(long, value_enum)]
+    convert_to: Option<InventoryFormat>,
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let config = advent::begin();
+    let args = advent::begin_with_args::<Args>();
+    let config = args.config;
 
     let elven_inventory_data = advent::data(&config).await?;
 
-    let elven_inventory_lines = elven_inventory_data.lines().collect::<Vec<&str>>();
+    let elf_inventories = || args.format.read(&elven_inventory_data, &args.separator);
 
-    let elven_inventory_line_clusters = elven_inventory_lines
-        .split(|elven_inventory_line| {
-            elven_inventory_line.is_empty() || !EMPTY_LINE_PATTERN.is_match(elven_inventory_line)
-        })
-        .map(|s| s.to_owned())
-        .collect::<Vec<Vec<&str>>>();
+    if let Some(format) = args.convert_to {
+        return format.write(elf_inventories(), stdout().lock());
+    }
 
-    let elven_inventory_calorie_counts = elven_inventory_line_clusters
-        .iter()
-        .map(|elven_inventory_line_cluster| {
-            elven_inventory_line_cluster
-                .iter()
-                .map(|elven_inventory_line| {
-                    elven_inventory_line.parse::<i32>().with_context(|| {
-                        format!("Failed to parse inventory line: {}", elven_inventory_line)
-                    })
-                })
-                .collect::<Result<Vec<i32>>>()
-        })
-        .collect::<Result<Vec<Vec<i32>>>>()?;
-
-    let mut elven_inventory_calorie_totals = elven_inventory_calorie_counts
-        .iter()
-        .map(|elven_inventory_calorie_counts| {
-            elven_inventory_calorie_counts
-                .iter()
-                .fold(0, |total_calories, calorie_count| {
-                    total_calories + calorie_count
-                })
-        })
-        .collect::<Vec<i32>>();
+    if args.stats {
+        let calorie_statistics =
+            CalorieStatistics::gather(calorie_totals(elf_inventories()), args.top.unwrap_or(3))?;
 
-    elven_inventory_calorie_totals.sort();
-    elven_inventory_calorie_totals.reverse();
+        print!("{}", calorie_statistics);
 
-    match config.part {
-        1 => {
-            let max_elven_inventory_calorie_total = elven_inventory_calorie_totals.first().unwrap();
+        return Ok(());
+    }
+
+    if let Some(top_count) = args.top {
+        let top_elf_calorie_totals =
+            top_calorie_totals(calorie_totals(elf_inventories()), top_count)?;
 
+        for elf_calorie_total in &top_elf_calorie_totals {
             println!(
-                "Maximum elven calorie total: {}",
-                max_elven_inventory_calorie_total
+                "Elf {}: {}",
+                elf_calorie_total.elf_number, elf_calorie_total.calorie_total
             );
         }
-        2 => {
-            let top_three_elven_inventory_calorie_totals = elven_inventory_calorie_totals
+
+        println!(
+            "Sum of top {} elven calorie totals: {}",
+            top_count,
+            top_elf_calorie_totals
                 .iter()
-                .take(3)
-                .collect::<Vec<&i32>>();
+                .map(|elf_calorie_total| elf_calorie_total.calorie_total)
+                .sum::<u128>()
+        );
 
-            println!(
-                "Top 3 elven calorie totals: {:?}",
-                top_three_elven_inventory_calorie_totals
-            );
+        return Ok(());
+    }
 
-            let top_three_elven_inventory_calorie_total_sum =
-                top_three_elven_inventory_calorie_totals
-                    .iter()
-                    .fold(0, |total_calories, calorie_count| {
-                        total_calories + *calorie_count
-                    });
+    let calorie_counting = CalorieCounting {
+        format: args.format,
+        separator: args.separator.clone(),
+    };
 
-            println!(
-                "Sum of top 3 elven calorie totals: {}",
-                top_three_elven_inventory_calorie_total_sum
-            );
-        }
-        _ => todo!(),
+    let answer = calorie_counting.solve(config.part, &elven_inventory_data)?;
+
+    match config.part {
+        1 => println!("Maximum elven calorie total: {}", answer),
+        _ => println!("Sum of top 3 elven calorie totals: {}", answer),
     }
 
     Ok(())
 }
 
-lazy_static! {
-  /// Regular expression designed to match strings containing purely whitespace.
-  static ref EMPTY_LINE_PATTERN: Regex =
-      Regex::new(r"\s*").unwrap();
+/// Solution to the "Calorie Counting" puzzle.
+#[derive(Default)]
+struct CalorieCounting {
+    /// Format that elves' inventories are written in.
+    format: InventoryFormat,
+    /// How elves' inventories are told apart in text.
+    separator: InventorySeparator,
+}
+
+impl Solution for CalorieCounting {
+    fn solve(&self, part: u8, elven_inventory_data: &str) -> Result<String> {
+        let top_count = match part {
+            1 => 1,
+            2 => 3,
+            _ => return Err(anyhow!("Part {} is not implemented", part)),
+        };
+
+        let top_elf_calorie_totals = top_calorie_totals(
+            calorie_totals(self.format.read(elven_inventory_data, &self.separator)),
+            top_count,
+        )?;
+
+        if top_elf_calorie_totals.is_empty() {
+            return Err(anyhow!("There were no elven inventories"));
+        }
+
+        let top_elf_calorie_total_sum = top_elf_calorie_totals
+            .iter()
+            .map(|elf_calorie_total| elf_calorie_total.calorie_total)
+            .sum::<u128>();
+
+        Ok(top_elf_calorie_total_sum.to_string())
+    }
 }
+
+advent::sample_tests!(CalorieCounting::default());
+advent::reference_tests!(
+    CalorieCounting::default(),
+    advent::generators::calorie_inventories()
+);
@@ -0,0 +1,131 @@
+use std::fmt::Display;
+
+use anyhow::{anyhow, Context, Result};
+
+/// Mapping from the letters in a column of a strategy guide to whatever they
+/// stand for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct Cipher<T>(Vec<(String, T)>);
+
+impl<T> Cipher<T> {
+    /// Creates and returns a new [Cipher] where the letter of each of
+    /// `entries` stands for its meaning.
+    pub(crate) fn new(entries: Vec<(String, T)>) -> Result<Cipher<T>> {
+        for (i, (letter, _)) in entries.iter().enumerate() {
+            if entries[..i]
+                .iter()
+                .any(|(other_letter, _)| other_letter == letter)
+            {
+                return Err(anyhow!("\"{}\" is deciphered more than once", letter));
+            }
+        }
+
+        Ok(Cipher(entries))
+    }
+
+    /// Returns the [Cipher] corresponding to the specified `encoded_cipher`,
+    /// which looks something like `"X=rock,Y=paper,Z=scissors"`.
+    ///
+    /// * `parse_meaning` returns what the text to the right of each `=` means
+    pub(crate) fn parse(
+        encoded_cipher: &str,
+        parse_meaning: impl Fn(&str) -> Result<T>,
+    ) -> Result<Cipher<T>> {
+        let entries = encoded_cipher
+            .split(',')
+            .map(|encoded_entry| {
+                let (letter, meaning) = encoded_entry.split_once('=').with_context(|| {
+                    format!(
+                        "\"{}\" should look like LETTER=MEANING",
+                        encoded_entry.trim()
+                    )
+                })?;
+
+                Ok((letter.trim().to_owned(), parse_meaning(meaning.trim())?))
+            })
+            .collect::<Result<Vec<(String, T)>>>()?;
+
+        Cipher::new(entries)
+    }
+
+    /// Returns what `letter` stands for, if anything.
+    pub(crate) fn decipher(&self, letter: &str) -> Option<&T> {
+        self.0
+            .iter()
+            .find(|(entry_letter, _)| entry_letter == letter)
+            .map(|(_, meaning)| meaning)
+    }
+}
+
+impl<T: Display> Display for Cipher<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let encoded_entries = self
+            .0
+            .iter()
+            .map(|(letter, meaning)| format!("{}={}", letter, meaning))
+            .collect::<Vec<String>>();
+
+        write!(f, "{}", encoded_entries.join(","))
+    }
+}
+
+/// Returns every [Cipher] that assigns a distinct one of `meanings` to each of
+/// `letters`.
+pub(crate) fn every_cipher<T: Clone>(letters: &[String], meanings: &[T]) -> Vec<Cipher<T>> {
+    let Some((letter, other_letters)) = letters.split_first() else {
+        return vec![Cipher(vec![])];
+    };
+
+    let mut ciphers = vec![];
+    for (i, meaning) in meanings.iter().enumerate() {
+        let other_meanings = meanings[..i]
+            .iter()
+            .chain(&meanings[i + 1..])
+            .cloned()
+            .collect::<Vec<T>>();
+
+        for Cipher(mut entries) in every_cipher(other_letters, &other_meanings) {
+            entries.insert(0, (letter.clone(), meaning.clone()));
+
+            ciphers.push(Cipher(entries));
+        }
+    }
+
+    ciphers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_round_trips() {
+        let cipher = Cipher::parse(" X=1, Y = 2 ", |meaning| Ok(meaning.parse::<u8>()?)).unwrap();
+
+        assert_eq!(cipher.decipher("Y"), Some(&2));
+        assert_eq!(cipher.decipher("Z"), None);
+        assert_eq!(cipher.to_string(), "X=1,Y=2");
+    }
+
+    #[test]
+    fn parse_fails_given_repeated_letters() {
+        assert!(Cipher::parse("X=1,X=2", |meaning| Ok(meaning.to_owned())).is_err());
+        assert!(Cipher::parse("X", |meaning| Ok(meaning.to_owned())).is_err());
+    }
+
+    #[test]
+    fn every_cipher_assigns_distinct_meanings() {
+        let letters = ["X".to_owned(), "Y".to_owned()];
+
+        let ciphers = every_cipher(&letters, &[1, 2, 3])
+            .iter()
+            .map(Cipher::to_string)
+            .collect::<Vec<String>>();
+
+        assert_eq!(
+            ciphers,
+            ["X=1,Y=2", "X=1,Y=3", "X=2,Y=1", "X=2,Y=3", "X=3,Y=1", "X=3,Y=2"]
+        );
+        assert!(every_cipher(&letters, &[1]).is_empty());
+    }
+}
@@ -0,0 +1,187 @@
+use anyhow::{anyhow, Result};
+use lazy_static::lazy_static;
+
+use crate::hand_shape::HandShape;
+
+/// Rules of a hand game in the vein of rock paper scissors: which hand shapes
+/// can be played, and which of them beat which.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct HandGame {
+    /// `beats[i][j]` is `true` if the `i`th hand shape beats the `j`th.
+    beats: Vec<Vec<bool>>,
+    /// Name of every hand shape, in ascending order of score.
+    shape_names: Vec<String>,
+}
+
+impl HandGame {
+    /// Creates and returns a new [HandGame].
+    ///
+    /// * `shape_names` names every hand shape, in ascending order of score
+    /// * `beats` lists pairs of hand shape names where the first beats the
+    ///   second; any other pair of distinct hand shapes draws
+    pub(crate) fn new(shape_names: &[&str], beats: &[(&str, &str)]) -> Result<HandGame> {
+        if shape_names.is_empty() {
+            return Err(anyhow!("A hand game needs at least one hand shape"));
+        }
+
+        let mut game = HandGame {
+            beats: vec![vec![false; shape_names.len()]; shape_names.len()],
+            shape_names: vec![],
+        };
+
+        for shape_name in shape_names {
+            if game.shape(shape_name).is_some() {
+                return Err(anyhow!("\"{}\" is listed more than once", shape_name));
+            }
+
+            game.shape_names.push(shape_name.to_lowercase());
+        }
+
+        for (winner_name, loser_name) in beats {
+            let index_of = |shape_name: &str| {
+                game.shape(shape_name)
+                    .map(|shape| shape.index())
+                    .ok_or_else(|| anyhow!("\"{}\" is not a hand shape in this game", shape_name))
+            };
+
+            let (winner, loser) = (index_of(winner_name)?, index_of(loser_name)?);
+            if winner == loser {
+                return Err(anyhow!("\"{}\" cannot beat itself", winner_name));
+            }
+            if game.beats[loser][winner] {
+                return Err(anyhow!(
+                    "\"{}\" and \"{}\" cannot both beat each other",
+                    winner_name,
+                    loser_name
+                ));
+            }
+
+            game.beats[winner][loser] = true;
+        }
+
+        Ok(game)
+    }
+
+    /// Creates and returns a balanced [HandGame] where every hand shape beats
+    /// the hand shapes an odd number of places before it (wrapping around) and
+    /// loses to the rest.
+    ///
+    /// * `shape_names` names every hand shape, in ascending order of score;
+    ///   there must be an odd number of them so that each hand shape beats as
+    ///   many others as it loses to
+    pub(crate) fn cyclic(shape_names: &[&str]) -> Result<HandGame> {
+        let shape_count = shape_names.len();
+        if shape_count.is_multiple_of(2) {
+            return Err(anyhow!(
+                "A cyclic hand game needs an odd number of hand shapes, not {}",
+                shape_count
+            ));
+        }
+
+        let beats = (0..shape_count)
+            .flat_map(|winner| {
+                (1..shape_count).step_by(2).map(move |distance| {
+                    let loser = (winner + shape_count - distance) % shape_count;
+
+                    (shape_names[winner], shape_names[loser])
+                })
+            })
+            .collect::<Vec<(&str, &str)>>();
+
+        HandGame::new(shape_names, &beats)
+    }
+
+    /// Returns the built-in [HandGame] named `name`: `rps` for rock paper
+    /// scissors, or `rpsls` for rock paper scissors lizard Spock.
+    pub(crate) fn built_in(name: &str) -> Result<&'static HandGame> {
+        lazy_static! {
+          /// Classic rock paper scissors.
+          static ref ROCK_PAPER_SCISSORS: HandGame =
+              HandGame::cyclic(&["rock", "paper", "scissors"]).unwrap();
+
+          /// Rock paper scissors, plus lizard and Spock.
+          static ref ROCK_PAPER_SCISSORS_LIZARD_SPOCK: HandGame =
+              HandGame::cyclic(&["rock", "paper", "scissors", "spock", "lizard"]).unwrap();
+        }
+
+        match name {
+            "rps" => Ok(&ROCK_PAPER_SCISSORS),
+            "rpsls" => Ok(&ROCK_PAPER_SCISSORS_LIZARD_SPOCK),
+            _ => Err(anyhow!("\"{}\" is not a built-in hand game", name)),
+        }
+    }
+
+    /// Returns the classic game of rock paper scissors.
+    pub(crate) fn rock_paper_scissors() -> &'static HandGame {
+        HandGame::built_in("rps").unwrap()
+    }
+
+    /// Returns `true` if the `winner`th hand shape beats the `loser`th.
+    pub(crate) fn beats(&self, winner: usize, loser: usize) -> bool {
+        self.beats[winner][loser]
+    }
+
+    /// Returns the hand shape named `name`, ignoring case, if there is one.
+    pub(crate) fn shape(&self, name: &str) -> Option<HandShape<'_>> {
+        self.shape_names
+            .iter()
+            .position(|shape_name| shape_name.eq_ignore_ascii_case(name))
+            .map(|index| HandShape::new(self, index))
+    }
+
+    /// Returns the name of the `index`th hand shape.
+    pub(crate) fn shape_name(&self, index: usize) -> &str {
+        &self.shape_names[index]
+    }
+
+    /// Returns every hand shape, in ascending order of score.
+    pub(crate) fn shapes(&self) -> impl Iterator<Item = HandShape<'_>> {
+        (0..self.shape_names.len()).map(|index| HandShape::new(self, index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rock_paper_scissors_lizard_spock_follows_the_rules() {
+        let game = HandGame::built_in("rpsls").unwrap();
+
+        let winners = [
+            ("scissors", "paper"),
+            ("paper", "rock"),
+            ("rock", "lizard"),
+            ("lizard", "spock"),
+            ("spock", "scissors"),
+            ("scissors", "lizard"),
+            ("lizard", "paper"),
+            ("paper", "spock"),
+            ("spock", "rock"),
+            ("rock", "scissors"),
+        ];
+
+        for winner in game.shapes() {
+            for loser in game.shapes() {
+                assert_eq!(
+                    winner.beats(&loser),
+                    winners.contains(&(winner.name(), loser.name())),
+                    "{} vs {}",
+                    winner.name(),
+                    loser.name()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn new_rejects_contradictory_rules() {
+        assert!(HandGame::new(&["rock", "rock"], &[]).is_err());
+        assert!(HandGame::new(&["rock"], &[("rock", "rock")]).is_err());
+        assert!(HandGame::new(&["rock"], &[("rock", "paper")]).is_err());
+        assert!(
+            HandGame::new(&["rock", "paper"], &[("rock", "paper"), ("paper", "rock")]).is_err()
+        );
+        assert!(HandGame::cyclic(&["rock", "paper"]).is_err());
+    }
+}
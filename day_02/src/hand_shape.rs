@@ -1,102 +1,106 @@
-use std::cmp::Ordering;
+use std::fmt::{Debug, Display};
+use std::ptr;
 
+use crate::hand_game::HandGame;
 use crate::round_outcome::RoundOutcome;
 use crate::scorable::Scorable;
 use anyhow::{anyhow, Result};
 
-/// Enumerates every usable hand shape in a rip roarin' game of rock paper
-/// scissors.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, PartialOrd)]
-pub(crate) enum HandShape {
-    /// Hand shape signified by an open palm. Paper beats rock.
-    Paper,
-    /// Hand shape signified by a closed fist. Rock beats scissors.
-    Rock,
-    /// Hand shape signified by a closed fist with only the index and middle
-    /// fingers sticking out. Rock beats scissors.
-    Scissors,
+/// Usable hand shape in a rip roarin' [HandGame] like rock paper scissors.
+#[derive(Clone, Copy)]
+pub(crate) struct HandShape<'a> {
+    /// Game that this [HandShape] is played in.
+    game: &'a HandGame,
+    /// Position of this [HandShape] among those of its game.
+    index: usize,
 }
 
-impl HandShape {
-    /// Returns the [HandShape] corresponding to the specified `encoded_round`
-    /// string targeted at the main player.
-    pub(crate) fn parse_for_me(encoded_round: &str) -> Result<HandShape> {
-        let sanitized_encoded_round = encoded_round.trim();
+impl<'a> HandShape<'a> {
+    /// Creates and returns the `index`th [HandShape] of `game`.
+    pub(crate) fn new(game: &'a HandGame, index: usize) -> HandShape<'a> {
+        HandShape { game, index }
+    }
 
-        match sanitized_encoded_round {
-            "X" => Ok(HandShape::Rock),
-            "Y" => Ok(HandShape::Paper),
-            "Z" => Ok(HandShape::Scissors),
-            _ => Err(anyhow!(
-                "\"{}\" is not a valid hand shape (for you, anyway)",
-                sanitized_encoded_round,
-            )),
-        }
+    /// Returns the [HandShape] of `game` named `name`, ignoring case.
+    pub(crate) fn parse(name: &str, game: &'a HandGame) -> Result<HandShape<'a>> {
+        game.shape(name)
+            .ok_or_else(|| anyhow!("\"{}\" is not a hand shape in this game", name))
+    }
+
+    /// Returns the first [HandShape] that would result in `round_outcome` if
+    /// played against the hand shape `against`, if there is one.
+    pub(crate) fn to_achieve(
+        round_outcome: &RoundOutcome,
+        against: &HandShape<'a>,
+    ) -> Result<HandShape<'a>> {
+        against
+            .game
+            .shapes()
+            .find(|hand_shape| hand_shape.outcome_against(against) == *round_outcome)
+            .ok_or_else(|| {
+                anyhow!(
+                    "No hand shape has a {:?} against {}",
+                    round_outcome,
+                    against
+                )
+            })
     }
 
-    /// Returns the [HandShape] corresponding to the specified `encoded_round`
-    /// string targeted at the opposing player.
-    pub(crate) fn parse_for_opponent(encoded_round: &str) -> Result<HandShape> {
-        let sanitized_encoded_round = encoded_round.trim();
+    /// Returns `true` if this [HandShape] beats `other`.
+    pub(crate) fn beats(&self, other: &HandShape) -> bool {
+        self.game.beats(self.index, other.index)
+    }
 
-        match sanitized_encoded_round {
-            "A" => Ok(HandShape::Rock),
-            "B" => Ok(HandShape::Paper),
-            "C" => Ok(HandShape::Scissors),
-            _ => Err(anyhow!(
-                "\"{}\" is not a valid hand shape (for your opponent, anyway)",
-                sanitized_encoded_round,
-            )),
-        }
+    /// Returns the game that this [HandShape] is played in.
+    pub(crate) fn game(&self) -> &'a HandGame {
+        self.game
     }
 
-    /// Returns the [HandShape] that would result in `round_outcome` if played
-    /// against this [HandShape] beats.
-    pub(crate) fn to_achieve(round_outcome: &RoundOutcome, against: &HandShape) -> HandShape {
-        match round_outcome {
-            RoundOutcome::Draw => *against,
-            RoundOutcome::Loss => match against {
-                Self::Paper => Self::Rock,
-                Self::Rock => Self::Scissors,
-                Self::Scissors => Self::Paper,
-            },
-            RoundOutcome::Win => match against {
-                Self::Paper => Self::Scissors,
-                Self::Rock => Self::Paper,
-                Self::Scissors => Self::Rock,
-            },
+    /// Returns the position of this [HandShape] among those of its game.
+    pub(crate) fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the name of this [HandShape].
+    pub(crate) fn name(&self) -> &'a str {
+        self.game.shape_name(self.index)
+    }
+
+    /// Returns the [RoundOutcome] of playing this [HandShape] against
+    /// `other`.
+    pub(crate) fn outcome_against(&self, other: &HandShape) -> RoundOutcome {
+        if self.beats(other) {
+            RoundOutcome::Win
+        } else if other.beats(self) {
+            RoundOutcome::Loss
+        } else {
+            RoundOutcome::Draw
         }
     }
 }
 
-impl Scorable for HandShape {
-    fn score(&self) -> u32 {
-        match self {
-            Self::Paper => 2,
-            Self::Rock => 1,
-            Self::Scissors => 3,
-        }
+impl Debug for HandShape<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("HandShape").field(&self.name()).finish()
     }
 }
 
-impl Ord for HandShape {
-    fn cmp(&self, other: &Self) -> Ordering {
-        match self {
-            Self::Paper => match other {
-                Self::Paper => Ordering::Equal,
-                Self::Rock => Ordering::Greater,
-                Self::Scissors => Ordering::Less,
-            },
-            Self::Rock => match other {
-                Self::Paper => Ordering::Less,
-                Self::Rock => Ordering::Equal,
-                Self::Scissors => Ordering::Greater,
-            },
-            Self::Scissors => match other {
-                Self::Paper => Ordering::Greater,
-                Self::Rock => Ordering::Less,
-                Self::Scissors => Ordering::Equal,
-            },
-        }
+impl Display for HandShape<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Eq for HandShape<'_> {}
+
+impl PartialEq for HandShape<'_> {
+    fn eq(&self, other: &Self) -> bool {
+        ptr::eq(self.game, other.game) && self.index == other.index
+    }
+}
+
+impl Scorable for HandShape<'_> {
+    fn score(&self) -> u32 {
+        self.index as u32 + 1
     }
 }
@@ -1,36 +1,273 @@
 extern crate advent;
 extern crate anyhow;
+extern crate clap;
 extern crate lazy_static;
 extern crate regex;
 extern crate tokio;
 
+mod cipher;
+mod hand_game;
 mod hand_shape;
 mod round;
 mod round_outcome;
 mod scorable;
 mod strategy_guide;
 mod strategy_guide_interpretation;
+mod strategy_report;
+mod tournament;
 
-use anyhow::Result;
+use advent::{Config, Solution};
+use anyhow::{anyhow, Context, Result};
+use cipher::Cipher;
+use clap::Parser;
+use hand_game::HandGame;
+use hand_shape::HandShape;
 use scorable::Scorable;
+use std::path::PathBuf;
 use strategy_guide::StrategyGuide;
-use strategy_guide_interpretation::StrategyGuideInterpretation;
+use strategy_guide_interpretation::{MyCipher, StrategyGuideInterpretation};
+use strategy_report::StrategyReport;
+use tournament::Tournament;
+
+/// Command line arguments for the "Rock Paper Scissors" puzzle.
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(flatten)]
+    config: Config,
+
+    /// Specifies which built-in hand game is played: `rps` for rock paper
+    /// scissors, or `rpsls` for rock paper scissors lizard Spock.
+    #[arg(default_value = "rps", long)]
+    game: String,
+
+    /// Specifies what the letters in the first column stand for, like
+    /// `A=rock,B=paper,C=scissors`, or `infer` to try every possibility.
+    #[arg(long)]
+    theirs: Option<String>,
+
+    /// Specifies what the letters in the second column stand for, like
+    /// `X=rock,Y=paper,Z=scissors` or `X=lose,Y=draw,Z=win`, or `infer` to try
+    /// every possibility.
+    #[arg(long)]
+    mine: Option<String>,
+
+    /// Reads `--theirs` and `--mine` from a file with a `theirs: ...` and/or
+    /// a `mine: ...` line; either option given on the command line wins.
+    #[arg(long)]
+    cipher: Option<PathBuf>,
+
+    /// Prints an analysis of the strategy guide instead of printing the
+    /// answer.
+    #[arg(long)]
+    report: bool,
+
+    /// Plays a round-robin tournament between the strategy guides in the
+    /// specified files instead of printing the answer.
+    #[arg(long, num_args = 2..)]
+    tournament: Vec<PathBuf>,
+}
+
+/// Value of `--theirs` or `--mine` that tries every possible cipher.
+const INFER: &str = "infer";
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let config = advent::begin();
+    let args = advent::begin_with_args::<Args>();
+    let config = args.config;
 
     let encoded_strategy_guide = advent::data(&config).await?;
-    let strategy_guide_interpretation = match config.part {
-        1 => StrategyGuideInterpretation::HandShape,
-        2 => StrategyGuideInterpretation::RoundOutcome,
-        _ => todo!(),
+
+    let game = HandGame::built_in(&args.game)?;
+
+    let (mut theirs, mut mine) = match &args.cipher {
+        Some(path) => read_cipher_file(path)?,
+        None => (None, None),
     };
+    theirs = args.theirs.or(theirs);
+    mine = args.mine.or(mine);
+
+    let opponent_cipher = theirs
+        .as_deref()
+        .filter(|theirs| *theirs != INFER)
+        .map(|theirs| Cipher::parse(theirs, |name| HandShape::parse(name, game)))
+        .transpose()?;
+    let my_cipher = mine
+        .as_deref()
+        .filter(|mine| *mine != INFER)
+        .map(|mine| MyCipher::parse(mine, game))
+        .transpose()?;
+
+    if theirs.as_deref() == Some(INFER) || mine.as_deref() == Some(INFER) {
+        let default_interpretation = match config.part {
+            1 => StrategyGuideInterpretation::hand_shape(game),
+            _ => StrategyGuideInterpretation::round_outcome(game),
+        };
+
+        let opponent_cipher = match theirs.as_deref() {
+            Some(INFER) => None,
+            _ => Some(opponent_cipher.unwrap_or(default_interpretation.opponent_cipher)),
+        };
+        let my_cipher = match mine.as_deref() {
+            Some(INFER) => None,
+            _ => Some(my_cipher.unwrap_or(default_interpretation.my_cipher)),
+        };
+
+        let mut scored_interpretations = StrategyGuideInterpretation::infer(
+            &encoded_strategy_guide,
+            game,
+            opponent_cipher,
+            my_cipher,
+        )
+        .context("Failed to infer how to interpret the strategy guide")?
+        .into_iter()
+        .map(|interpretation| {
+            let strategy_guide = StrategyGuide::parse(&encoded_strategy_guide, &interpretation)?;
+
+            Ok((strategy_guide.score(), interpretation))
+        })
+        .collect::<Result<Vec<(u32, StrategyGuideInterpretation)>>>()?;
+
+        scored_interpretations.sort_by_key(|(score, _)| std::cmp::Reverse(*score));
+
+        for (score, interpretation) in scored_interpretations {
+            println!("{}\t{}", score, interpretation);
+        }
+
+        return Ok(());
+    }
+
+    let rock_paper_scissors = RockPaperScissors {
+        game,
+        my_cipher,
+        opponent_cipher,
+    };
+
+    if !args.tournament.is_empty() {
+        let interpretation = rock_paper_scissors.interpretation(config.part)?;
+
+        let entrants = args
+            .tournament
+            .iter()
+            .map(|path| {
+                let encoded_strategy_guide = std::fs::read_to_string(path).with_context(|| {
+                    format!("Failed to read strategy guide \"{}\"", path.display())
+                })?;
+                let strategy_guide = StrategyGuide::parse(&encoded_strategy_guide, &interpretation)
+                    .with_context(|| format!("Failed to parse \"{}\"", path.display()))?;
 
-    let strategy_guide =
-        StrategyGuide::parse(&encoded_strategy_guide, strategy_guide_interpretation)?;
+                Ok((path.display().to_string(), strategy_guide))
+            })
+            .collect::<Result<Vec<(String, StrategyGuide)>>>()?;
 
-    println!("Total score of strategy guide: {}", strategy_guide.score());
+        print!("{}", Tournament::play(entrants));
+
+        return Ok(());
+    }
+
+    if args.report {
+        let strategy_guide = StrategyGuide::parse(
+            &encoded_strategy_guide,
+            &rock_paper_scissors.interpretation(config.part)?,
+        )?;
+
+        print!("{}", StrategyReport::gather(&strategy_guide, game));
+
+        return Ok(());
+    }
+
+    let answer = rock_paper_scissors.solve(config.part, &encoded_strategy_guide)?;
+
+    println!("Total score of strategy guide: {}", answer);
 
     Ok(())
 }
+
+/// Returns the `theirs` and `mine` ciphers written in the cipher file at
+/// `path`, if it has them.
+///
+/// Blank lines and lines starting with `#` are ignored.
+fn read_cipher_file(path: &PathBuf) -> Result<(Option<String>, Option<String>)> {
+    let cipher_file = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read cipher file \"{}\"", path.display()))?;
+
+    let (mut theirs, mut mine) = (None, None);
+    for (i, line) in cipher_file.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        match line.split_once(':') {
+            Some((key, cipher)) if key.trim() == "theirs" => {
+                theirs = Some(cipher.trim().to_owned())
+            }
+            Some((key, cipher)) if key.trim() == "mine" => mine = Some(cipher.trim().to_owned()),
+            _ => {
+                return Err(anyhow!(
+                    "Line {} of the cipher file should start with \"theirs:\" or \"mine:\"",
+                    i + 1
+                ))
+            }
+        }
+    }
+
+    Ok((theirs, mine))
+}
+
+/// Solution to the "Rock Paper Scissors" puzzle.
+struct RockPaperScissors {
+    /// Hand game that the strategy guide is for.
+    game: &'static HandGame,
+    /// What the letters in the second column stand for, if not what the part
+    /// being solved says.
+    my_cipher: Option<MyCipher<'static>>,
+    /// What the letters in the first column stand for, if not what the part
+    /// being solved says.
+    opponent_cipher: Option<Cipher<HandShape<'static>>>,
+}
+
+impl Default for RockPaperScissors {
+    fn default() -> Self {
+        RockPaperScissors {
+            game: HandGame::rock_paper_scissors(),
+            my_cipher: None,
+            opponent_cipher: None,
+        }
+    }
+}
+
+impl RockPaperScissors {
+    /// Returns the [StrategyGuideInterpretation] to use for the specified
+    /// `part`.
+    fn interpretation(&self, part: u8) -> Result<StrategyGuideInterpretation<'static>> {
+        let mut strategy_guide_interpretation = match part {
+            1 => StrategyGuideInterpretation::hand_shape(self.game),
+            2 => StrategyGuideInterpretation::round_outcome(self.game),
+            _ => return Err(anyhow!("Part {} is not implemented", part)),
+        };
+
+        if let Some(my_cipher) = &self.my_cipher {
+            strategy_guide_interpretation.my_cipher = my_cipher.clone();
+        }
+        if let Some(opponent_cipher) = &self.opponent_cipher {
+            strategy_guide_interpretation.opponent_cipher = opponent_cipher.clone();
+        }
+
+        Ok(strategy_guide_interpretation)
+    }
+}
+
+impl Solution for RockPaperScissors {
+    fn solve(&self, part: u8, encoded_strategy_guide: &str) -> Result<String> {
+        let strategy_guide =
+            StrategyGuide::parse(encoded_strategy_guide, &self.interpretation(part)?)?;
+
+        Ok(strategy_guide.score().to_string())
+    }
+}
+
+advent::sample_tests!(RockPaperScissors::default());
+advent::reference_tests!(
+    RockPaperScissors::default(),
+    advent::generators::strategy_guides()
+);
@@ -1,4 +1,3 @@
-use std::cmp::Ordering;
 use std::fmt::Debug;
 
 use crate::{
@@ -11,14 +10,14 @@ use regex::Regex;
 
 /// Represents one round of a rip roarin' game of rock paper scissors.
 #[derive(Eq, PartialEq)]
-pub(crate) struct Round {
+pub(crate) struct Round<'a> {
     /// Hand shape submitted by this player of rock paper scissors.
-    pub(crate) my_hand_shape: HandShape,
+    pub(crate) my_hand_shape: HandShape<'a>,
     /// Hand shape submitted by the other player of rock paper scissors.
-    pub(crate) opponent_hand_shape: HandShape,
+    pub(crate) opponent_hand_shape: HandShape<'a>,
 }
 
-impl Round {
+impl<'a> Round<'a> {
     /// Returns the constructed [Round] that best corresponds to the specified
     /// `encoded_round`.
     ///
@@ -26,8 +25,8 @@ impl Round {
     ///   `encoded_round`
     pub(crate) fn parse(
         encoded_round: &str,
-        interpretation: StrategyGuideInterpretation,
-    ) -> Result<Round> {
+        interpretation: &StrategyGuideInterpretation<'a>,
+    ) -> Result<Round<'a>> {
         lazy_static! {
           /// Regular expression designed to match rounds encoded within a strategy
           /// guide. A round might look like `"A  X"` or `"  B Y"`.
@@ -42,34 +41,23 @@ impl Round {
         let first_chunk = parsed_encoded_round.get(1).unwrap().as_str();
         let second_chunk = parsed_encoded_round.get(2).unwrap().as_str();
 
-        let opponent_hand_shape = HandShape::parse_for_opponent(first_chunk)?;
-
-        let my_hand_shape = match interpretation {
-            StrategyGuideInterpretation::HandShape => HandShape::parse_for_me(second_chunk),
-            StrategyGuideInterpretation::RoundOutcome => {
-                let round_outcome = RoundOutcome::parse(second_chunk)?;
-
-                Ok(HandShape::to_achieve(&round_outcome, &opponent_hand_shape))
-            }
-        }?;
+        let opponent_hand_shape = interpretation.decipher_opponent(first_chunk)?;
+        let my_hand_shape = interpretation.decipher_mine(second_chunk, &opponent_hand_shape)?;
 
         Ok(Round {
-            my_hand_shape: my_hand_shape,
-            opponent_hand_shape: opponent_hand_shape,
+            my_hand_shape,
+            opponent_hand_shape,
         })
     }
 
     /// Derives the outcome of this [Round].
     pub(crate) fn outcome(&self) -> RoundOutcome {
-        match self.my_hand_shape.cmp(&self.opponent_hand_shape) {
-            Ordering::Equal => RoundOutcome::Draw,
-            Ordering::Greater => RoundOutcome::Win,
-            Ordering::Less => RoundOutcome::Loss,
-        }
+        self.my_hand_shape
+            .outcome_against(&self.opponent_hand_shape)
     }
 }
 
-impl Debug for Round {
+impl Debug for Round<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.debug_struct("Round")
             .field("my_hand_shape", &self.my_hand_shape)
@@ -80,7 +68,7 @@ impl Debug for Round {
     }
 }
 
-impl Scorable for Round {
+impl Scorable for Round<'_> {
     fn score(&self) -> u32 {
         self.my_hand_shape.score() + self.outcome().score()
     }
@@ -89,70 +77,91 @@ impl Scorable for Round {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::hand_game::HandGame;
+
+    /// Returns the rock paper scissors [HandShape] named `name`.
+    fn shape(name: &str) -> HandShape<'static> {
+        HandGame::rock_paper_scissors().shape(name).unwrap()
+    }
+
+    /// Returns the [Round] corresponding to `encoded_round` in a game of rock
+    /// paper scissors.
+    ///
+    /// * `interpretation` returns the [StrategyGuideInterpretation] to use for
+    ///   a given game
+    fn parse(
+        encoded_round: &str,
+        interpretation: fn(&'static HandGame) -> StrategyGuideInterpretation<'static>,
+    ) -> Result<Round<'static>> {
+        Round::parse(
+            encoded_round,
+            &interpretation(HandGame::rock_paper_scissors()),
+        )
+    }
 
     #[test]
     fn correctly_parses_valid_encoded_rounds() {
         assert_eq!(
-            Round::parse("  A X", StrategyGuideInterpretation::HandShape).unwrap(),
+            parse("  A X", StrategyGuideInterpretation::hand_shape).unwrap(),
             Round {
-                my_hand_shape: HandShape::Rock,
-                opponent_hand_shape: HandShape::Rock,
+                my_hand_shape: shape("rock"),
+                opponent_hand_shape: shape("rock"),
             },
         );
         assert_eq!(
-            Round::parse("  A X", StrategyGuideInterpretation::RoundOutcome).unwrap(),
+            parse("  A X", StrategyGuideInterpretation::round_outcome).unwrap(),
             Round {
-                my_hand_shape: HandShape::Scissors,
-                opponent_hand_shape: HandShape::Rock,
+                my_hand_shape: shape("scissors"),
+                opponent_hand_shape: shape("rock"),
             },
         );
 
         assert_eq!(
-            Round::parse("B\nY", StrategyGuideInterpretation::HandShape).unwrap(),
+            parse("B\nY", StrategyGuideInterpretation::hand_shape).unwrap(),
             Round {
-                my_hand_shape: HandShape::Paper,
-                opponent_hand_shape: HandShape::Paper,
+                my_hand_shape: shape("paper"),
+                opponent_hand_shape: shape("paper"),
             },
         );
         assert_eq!(
-            Round::parse("B\nY", StrategyGuideInterpretation::RoundOutcome).unwrap(),
+            parse("B\nY", StrategyGuideInterpretation::round_outcome).unwrap(),
             Round {
-                my_hand_shape: HandShape::Paper,
-                opponent_hand_shape: HandShape::Paper,
+                my_hand_shape: shape("paper"),
+                opponent_hand_shape: shape("paper"),
             },
         );
 
         assert_eq!(
-            Round::parse("A\nZ", StrategyGuideInterpretation::HandShape).unwrap(),
+            parse("A\nZ", StrategyGuideInterpretation::hand_shape).unwrap(),
             Round {
-                my_hand_shape: HandShape::Scissors,
-                opponent_hand_shape: HandShape::Rock,
+                my_hand_shape: shape("scissors"),
+                opponent_hand_shape: shape("rock"),
             },
         );
         assert_eq!(
-            Round::parse("A\nZ", StrategyGuideInterpretation::RoundOutcome).unwrap(),
+            parse("A\nZ", StrategyGuideInterpretation::round_outcome).unwrap(),
             Round {
-                my_hand_shape: HandShape::Paper,
-                opponent_hand_shape: HandShape::Rock,
+                my_hand_shape: shape("paper"),
+                opponent_hand_shape: shape("rock"),
             },
         );
     }
 
     #[test]
     fn correctly_parses_invalid_encoded_rounds() {
-        assert!(Round::parse("  a X", StrategyGuideInterpretation::HandShape).is_err());
-        assert!(Round::parse("  a X", StrategyGuideInterpretation::RoundOutcome).is_err());
+        assert!(parse("  a X", StrategyGuideInterpretation::hand_shape).is_err());
+        assert!(parse("  a X", StrategyGuideInterpretation::round_outcome).is_err());
 
-        assert!(Round::parse("BY", StrategyGuideInterpretation::HandShape).is_err());
-        assert!(Round::parse("BY", StrategyGuideInterpretation::RoundOutcome).is_err());
+        assert!(parse("BY", StrategyGuideInterpretation::hand_shape).is_err());
+        assert!(parse("BY", StrategyGuideInterpretation::round_outcome).is_err());
     }
 
     #[test]
     fn correctly_parses_reversed_encoded_rounds() {
-        assert!(Round::parse("  X A", StrategyGuideInterpretation::HandShape).is_err());
-        assert!(Round::parse("  X A", StrategyGuideInterpretation::RoundOutcome).is_err());
+        assert!(parse("  X A", StrategyGuideInterpretation::hand_shape).is_err());
+        assert!(parse("  X A", StrategyGuideInterpretation::round_outcome).is_err());
 
-        assert!(Round::parse("Y\nB", StrategyGuideInterpretation::HandShape).is_err());
-        assert!(Round::parse("Y\nB", StrategyGuideInterpretation::RoundOutcome).is_err());
+        assert!(parse("Y\nB", StrategyGuideInterpretation::hand_shape).is_err());
+        assert!(parse("Y\nB", StrategyGuideInterpretation::round_outcome).is_err());
     }
 }
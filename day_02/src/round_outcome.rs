@@ -1,8 +1,10 @@
+use std::fmt::Display;
+
 use crate::scorable::Scorable;
 use anyhow::{anyhow, Result};
 
 /// Enumerates every outcome for the main player of a particular [Round].
-#[derive(Debug)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) enum RoundOutcome {
     /// Outcome that results from both players playing the same hand shape.
     Draw,
@@ -15,17 +17,27 @@ pub(crate) enum RoundOutcome {
 }
 
 impl RoundOutcome {
-    /// Returns the [RoundOutcome] that best corresponds to the specified
-    /// `encoded_round_outcome`.
-    pub(crate) fn parse(encoded_round_outcome: &str) -> Result<RoundOutcome> {
-        match encoded_round_outcome {
-            "X" => Ok(RoundOutcome::Loss),
-            "Y" => Ok(RoundOutcome::Draw),
-            "Z" => Ok(RoundOutcome::Win),
-            _ => Err(anyhow!(
-                "\"{}\" is not a valid round outcome",
-                encoded_round_outcome
-            )),
+    /// Every [RoundOutcome], from worst to best.
+    pub(crate) const ALL: [RoundOutcome; 3] =
+        [RoundOutcome::Loss, RoundOutcome::Draw, RoundOutcome::Win];
+
+    /// Returns the [RoundOutcome] named `name`: `lose`, `draw`, or `win`.
+    pub(crate) fn from_name(name: &str) -> Result<RoundOutcome> {
+        match name.to_lowercase().as_str() {
+            "lose" | "loss" => Ok(RoundOutcome::Loss),
+            "draw" => Ok(RoundOutcome::Draw),
+            "win" => Ok(RoundOutcome::Win),
+            _ => Err(anyhow!("\"{}\" is not a valid round outcome", name)),
+        }
+    }
+}
+
+impl Display for RoundOutcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Draw => write!(f, "draw"),
+            Self::Loss => write!(f, "lose"),
+            Self::Win => write!(f, "win"),
         }
     }
 }
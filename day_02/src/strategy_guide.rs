@@ -6,9 +6,9 @@ use anyhow::{Context, Result};
 /// Series of rock-paper-scissors rounds that dictate how each player should
 /// play in each phase of the game.
 #[derive(Debug)]
-pub(crate) struct StrategyGuide(Vec<Round>);
+pub(crate) struct StrategyGuide<'a>(Vec<Round<'a>>);
 
-impl StrategyGuide {
+impl<'a> StrategyGuide<'a> {
     /// Returns a [StrategyGuide] corresponding to the specified
     /// `encoded_strategy_guide` string.
     ///
@@ -16,8 +16,8 @@ impl StrategyGuide {
     ///   `encoded_strategy_guide`
     pub(crate) fn parse(
         encoded_strategy_guide: &str,
-        interpretation: StrategyGuideInterpretation,
-    ) -> Result<StrategyGuide> {
+        interpretation: &StrategyGuideInterpretation<'a>,
+    ) -> Result<StrategyGuide<'a>> {
         let rounds = encoded_strategy_guide
             .lines()
             .map(|encoded_strategy_guide_line| {
@@ -28,9 +28,14 @@ impl StrategyGuide {
 
         Ok(StrategyGuide(rounds))
     }
+
+    /// Returns every [Round] of this [StrategyGuide], in order.
+    pub(crate) fn rounds(&self) -> &[Round<'a>] {
+        &self.0
+    }
 }
 
-impl Scorable for StrategyGuide {
+impl Scorable for StrategyGuide<'_> {
     fn score(&self) -> u32 {
         self.0.iter().fold(0, |acc, round| acc + round.score())
     }
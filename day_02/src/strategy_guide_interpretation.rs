@@ -1,9 +1,278 @@
-/// Enumerates every way that "X", "Y", "Z" could be interpreted in the strategy
-/// guide.
-#[derive(Clone, Copy, Debug)]
-pub(crate) enum StrategyGuideInterpretation {
-    /// "X", "Y", "Z" map to hand shapes.
-    HandShape,
-    /// "X", "Y", "Z" map to round outcomes.
-    RoundOutcome,
+use std::collections::BTreeSet;
+use std::fmt::Display;
+
+use anyhow::{anyhow, Result};
+
+use crate::cipher::{every_cipher, Cipher};
+use crate::hand_game::HandGame;
+use crate::hand_shape::HandShape;
+use crate::round_outcome::RoundOutcome;
+
+/// Describes what the letters in each column of the strategy guide stand for.
+///
+/// Letters that a [Cipher] does not cover can still spell out a hand shape by
+/// name.
+#[derive(Clone, Debug)]
+pub(crate) struct StrategyGuideInterpretation<'a> {
+    /// Hand game that the strategy guide is for.
+    pub(crate) game: &'a HandGame,
+    /// What the letters in the second column stand for.
+    pub(crate) my_cipher: MyCipher<'a>,
+    /// Hand shapes that the letters in the first column stand for.
+    pub(crate) opponent_cipher: Cipher<HandShape<'a>>,
+}
+
+/// Enumerates every kind of thing that the letters in the second column of the
+/// strategy guide could stand for.
+#[derive(Clone, Debug)]
+pub(crate) enum MyCipher<'a> {
+    /// Letters stand for the hand shape to play.
+    HandShape(Cipher<HandShape<'a>>),
+    /// Letters stand for the outcome to bring about.
+    RoundOutcome(Cipher<RoundOutcome>),
+}
+
+impl<'a> MyCipher<'a> {
+    /// Returns the [MyCipher] corresponding to the specified
+    /// `encoded_cipher`, whose letters stand either for round outcomes
+    /// (`lose`, `draw`, `win`) or for hand shapes of `game`.
+    pub(crate) fn parse(encoded_cipher: &str, game: &'a HandGame) -> Result<MyCipher<'a>> {
+        if let Ok(cipher) = Cipher::parse(encoded_cipher, RoundOutcome::from_name) {
+            return Ok(MyCipher::RoundOutcome(cipher));
+        }
+
+        Cipher::parse(encoded_cipher, |name| HandShape::parse(name, game)).map(MyCipher::HandShape)
+    }
+}
+
+impl Display for MyCipher<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MyCipher::HandShape(cipher) => write!(f, "{}", cipher),
+            MyCipher::RoundOutcome(cipher) => write!(f, "{}", cipher),
+        }
+    }
+}
+
+impl<'a> StrategyGuideInterpretation<'a> {
+    /// Returns the [StrategyGuideInterpretation] where "A", "B", "C" and "X",
+    /// "Y", "Z" stand for the first three hand shapes of `game`.
+    pub(crate) fn hand_shape(game: &'a HandGame) -> StrategyGuideInterpretation<'a> {
+        StrategyGuideInterpretation {
+            game,
+            my_cipher: MyCipher::HandShape(letter_cipher(["X", "Y", "Z"], game.shapes())),
+            opponent_cipher: letter_cipher(["A", "B", "C"], game.shapes()),
+        }
+    }
+
+    /// Returns the [StrategyGuideInterpretation] where "A", "B", "C" stand for
+    /// the first three hand shapes of `game`, and "X", "Y", "Z" stand for
+    /// losing, drawing, and winning.
+    pub(crate) fn round_outcome(game: &'a HandGame) -> StrategyGuideInterpretation<'a> {
+        StrategyGuideInterpretation {
+            game,
+            my_cipher: MyCipher::RoundOutcome(letter_cipher(["X", "Y", "Z"], RoundOutcome::ALL)),
+            opponent_cipher: letter_cipher(["A", "B", "C"], game.shapes()),
+        }
+    }
+
+    /// Returns every [StrategyGuideInterpretation] of `encoded_strategy_guide`
+    /// that deciphers each letter in its columns differently.
+    ///
+    /// * `opponent_cipher` and `my_cipher` are used as is when given, and
+    ///   otherwise every way of deciphering their column is tried
+    ///
+    /// Returns an [Err] if a column to be deciphered has more distinct letters
+    /// than there are things for them to stand for.
+    pub(crate) fn infer(
+        encoded_strategy_guide: &str,
+        game: &'a HandGame,
+        opponent_cipher: Option<Cipher<HandShape<'a>>>,
+        my_cipher: Option<MyCipher<'a>>,
+    ) -> Result<Vec<StrategyGuideInterpretation<'a>>> {
+        let shapes = game.shapes().collect::<Vec<HandShape>>();
+
+        let opponent_ciphers = match opponent_cipher {
+            Some(opponent_cipher) => vec![opponent_cipher],
+            None => {
+                let opponent_letters = column_letters(encoded_strategy_guide, 0);
+                if opponent_letters.len() > shapes.len() {
+                    return Err(anyhow!(
+                        "Column 1 has {} distinct letters, but there are only {} hand shapes",
+                        opponent_letters.len(),
+                        shapes.len()
+                    ));
+                }
+
+                every_cipher(&opponent_letters, &shapes)
+            }
+        };
+
+        let my_ciphers = match my_cipher {
+            Some(my_cipher) => vec![my_cipher],
+            None => {
+                let my_letters = column_letters(encoded_strategy_guide, 1);
+                if my_letters.len() > shapes.len().max(RoundOutcome::ALL.len()) {
+                    return Err(anyhow!(
+                        "Column 2 has {} distinct letters, but there are only {} hand shapes \
+                         and {} round outcomes",
+                        my_letters.len(),
+                        shapes.len(),
+                        RoundOutcome::ALL.len()
+                    ));
+                }
+
+                every_cipher(&my_letters, &shapes)
+                    .into_iter()
+                    .map(MyCipher::HandShape)
+                    .chain(
+                        every_cipher(&my_letters, &RoundOutcome::ALL)
+                            .into_iter()
+                            .map(MyCipher::RoundOutcome),
+                    )
+                    .collect()
+            }
+        };
+
+        Ok(opponent_ciphers
+            .iter()
+            .flat_map(|opponent_cipher| {
+                my_ciphers
+                    .iter()
+                    .map(|my_cipher| StrategyGuideInterpretation {
+                        game,
+                        my_cipher: my_cipher.clone(),
+                        opponent_cipher: opponent_cipher.clone(),
+                    })
+            })
+            .collect())
+    }
+
+    /// Returns the hand shape that `letter` in the first column stands for.
+    pub(crate) fn decipher_opponent(&self, letter: &str) -> Result<HandShape<'a>> {
+        match self.opponent_cipher.decipher(letter) {
+            Some(hand_shape) => Ok(*hand_shape),
+            None => HandShape::parse(letter, self.game).map_err(|_| {
+                anyhow!(
+                    "\"{}\" is not a valid hand shape (for your opponent, anyway)",
+                    letter
+                )
+            }),
+        }
+    }
+
+    /// Returns the hand shape that `letter` in the second column calls for
+    /// when the opponent plays `opponent_hand_shape`.
+    pub(crate) fn decipher_mine(
+        &self,
+        letter: &str,
+        opponent_hand_shape: &HandShape<'a>,
+    ) -> Result<HandShape<'a>> {
+        match &self.my_cipher {
+            MyCipher::HandShape(cipher) => match cipher.decipher(letter) {
+                Some(hand_shape) => Ok(*hand_shape),
+                None => HandShape::parse(letter, self.game).map_err(|_| {
+                    anyhow!("\"{}\" is not a valid hand shape (for you, anyway)", letter)
+                }),
+            },
+            MyCipher::RoundOutcome(cipher) => {
+                let round_outcome = cipher
+                    .decipher(letter)
+                    .ok_or_else(|| anyhow!("\"{}\" is not a valid round outcome", letter))?;
+
+                HandShape::to_achieve(round_outcome, opponent_hand_shape)
+            }
+        }
+    }
+}
+
+impl Display for StrategyGuideInterpretation<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "--theirs {} --mine {}",
+            self.opponent_cipher, self.my_cipher
+        )
+    }
+}
+
+/// Returns the [Cipher] where each of `letters` stands for the corresponding
+/// one of `meanings`.
+fn letter_cipher<T>(letters: [&str; 3], meanings: impl IntoIterator<Item = T>) -> Cipher<T> {
+    Cipher::new(
+        letters
+            .iter()
+            .map(|letter| letter.to_string())
+            .zip(meanings)
+            .collect(),
+    )
+    .unwrap()
+}
+
+/// Returns every distinct letter in the `column_index`th column of
+/// `encoded_strategy_guide`, in order.
+fn column_letters(encoded_strategy_guide: &str, column_index: usize) -> Vec<String> {
+    encoded_strategy_guide
+        .lines()
+        .filter_map(|line| line.split_whitespace().nth(column_index))
+        .map(String::from)
+        .collect::<BTreeSet<String>>()
+        .into_iter()
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn infer_tries_every_unknown_cipher() {
+        let game = HandGame::rock_paper_scissors();
+
+        let interpretations =
+            StrategyGuideInterpretation::infer("A Y\nB X\nC Z", game, None, None).unwrap();
+        // 3! opponent ciphers, times 3! hand shape and 3! round outcome ciphers.
+        assert_eq!(interpretations.len(), 6 * 12);
+
+        let opponent_cipher = StrategyGuideInterpretation::hand_shape(game).opponent_cipher;
+        let interpretations =
+            StrategyGuideInterpretation::infer("A Y\nA Q", game, Some(opponent_cipher), None)
+                .unwrap();
+        // Only two of the three hand shapes or round outcomes are needed.
+        assert_eq!(interpretations.len(), 12);
+    }
+
+    #[test]
+    fn infer_fails_given_too_many_letters() {
+        let game = HandGame::rock_paper_scissors();
+
+        assert_eq!(
+            StrategyGuideInterpretation::infer("A X\nB X\nC X\nD X", game, None, None)
+                .unwrap_err()
+                .to_string(),
+            "Column 1 has 4 distinct letters, but there are only 3 hand shapes"
+        );
+        assert_eq!(
+            StrategyGuideInterpretation::infer("A W\nA X\nA Y\nA Z", game, None, None)
+                .unwrap_err()
+                .to_string(),
+            "Column 2 has 4 distinct letters, but there are only 3 hand shapes and 3 round \
+             outcomes"
+        );
+    }
+
+    #[test]
+    fn my_cipher_parse_tells_outcomes_from_hand_shapes() {
+        let game = HandGame::rock_paper_scissors();
+
+        assert!(matches!(
+            MyCipher::parse("X=lose,Y=draw,Z=win", game),
+            Ok(MyCipher::RoundOutcome(_))
+        ));
+        assert!(matches!(
+            MyCipher::parse("X=rock,Y=paper,Z=scissors", game),
+            Ok(MyCipher::HandShape(_))
+        ));
+        assert!(MyCipher::parse("X=rock,Y=win", game).is_err());
+    }
 }
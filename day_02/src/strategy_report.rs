@@ -0,0 +1,204 @@
+use std::fmt::Display;
+
+use crate::hand_game::HandGame;
+use crate::hand_shape::HandShape;
+use crate::round::Round;
+use crate::round_outcome::RoundOutcome;
+use crate::scorable::Scorable;
+use crate::strategy_guide::StrategyGuide;
+
+/// Analysis of how well a [StrategyGuide] plays, and how well it could have.
+#[derive(Debug)]
+pub(crate) struct StrategyReport<'a> {
+    /// Hand game that the strategy guide is for.
+    game: &'a HandGame,
+    /// Score that the strategy guide would get, on average, against an
+    /// opponent playing every hand shape with equal probability.
+    expected_random_score: f64,
+    /// Score that the strategy guide would get if the best hand shape were
+    /// played every round.
+    optimal_score: u32,
+    /// How many times each [RoundOutcome] happens against each opponent hand
+    /// shape, indexed by hand shape and then by position in
+    /// [RoundOutcome::ALL].
+    outcome_counts_by_opponent_hand_shape: Vec<[u32; 3]>,
+    /// How many rounds the strategy guide has.
+    round_count: usize,
+    /// Points scored for playing each hand shape, indexed by hand shape, split
+    /// into points for the hand shape itself and points for the outcome.
+    scores_by_my_hand_shape: Vec<(u32, u32)>,
+}
+
+impl<'a> StrategyReport<'a> {
+    /// Returns the [StrategyReport] of `strategy_guide`, which is for `game`.
+    pub(crate) fn gather(
+        strategy_guide: &StrategyGuide<'a>,
+        game: &'a HandGame,
+    ) -> StrategyReport<'a> {
+        let shape_count = game.shapes().count();
+
+        let mut report = StrategyReport {
+            game,
+            expected_random_score: 0.0,
+            optimal_score: 0,
+            outcome_counts_by_opponent_hand_shape: vec![[0; 3]; shape_count],
+            round_count: strategy_guide.rounds().len(),
+            scores_by_my_hand_shape: vec![(0, 0); shape_count],
+        };
+
+        for round in strategy_guide.rounds() {
+            let outcome = round.outcome();
+            let outcome_index = RoundOutcome::ALL
+                .iter()
+                .position(|round_outcome| *round_outcome == outcome)
+                .unwrap();
+
+            report.outcome_counts_by_opponent_hand_shape[round.opponent_hand_shape.index()]
+                [outcome_index] += 1;
+
+            let (hand_shape_score, outcome_score) =
+                &mut report.scores_by_my_hand_shape[round.my_hand_shape.index()];
+            *hand_shape_score += round.my_hand_shape.score();
+            *outcome_score += outcome.score();
+
+            report.optimal_score += optimal_round(&round.opponent_hand_shape).score();
+            report.expected_random_score += game
+                .shapes()
+                .map(|opponent_hand_shape| {
+                    Round {
+                        my_hand_shape: round.my_hand_shape,
+                        opponent_hand_shape,
+                    }
+                    .score() as f64
+                })
+                .sum::<f64>()
+                / shape_count as f64;
+        }
+
+        report
+    }
+
+    /// Returns the total score of the strategy guide.
+    pub(crate) fn score(&self) -> u32 {
+        self.scores_by_my_hand_shape
+            .iter()
+            .map(|(hand_shape_score, outcome_score)| hand_shape_score + outcome_score)
+            .sum()
+    }
+}
+
+impl Display for StrategyReport<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let score = self.score();
+
+        writeln!(f, "Rounds: {}", self.round_count)?;
+        writeln!(f, "Score: {}", score)?;
+        writeln!(
+            f,
+            "Optimal score: {} ({} more than the strategy guide)",
+            self.optimal_score,
+            self.optimal_score - score
+        )?;
+        writeln!(
+            f,
+            "Expected score against a random opponent: {:.1}",
+            self.expected_random_score
+        )?;
+
+        let name_width = self
+            .game
+            .shapes()
+            .map(|hand_shape| hand_shape.name().len())
+            .max()
+            .unwrap_or(0);
+
+        writeln!(f)?;
+        writeln!(f, "Outcomes by opponent hand shape:")?;
+        for (hand_shape, [losses, draws, wins]) in self
+            .game
+            .shapes()
+            .zip(&self.outcome_counts_by_opponent_hand_shape)
+        {
+            writeln!(
+                f,
+                "  {:<width$} {} won, {} drawn, {} lost",
+                hand_shape.name(),
+                wins,
+                draws,
+                losses,
+                width = name_width
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Score by hand shape played:")?;
+        for (hand_shape, (hand_shape_score, outcome_score)) in
+            self.game.shapes().zip(&self.scores_by_my_hand_shape)
+        {
+            writeln!(
+                f,
+                "  {:<width$} {} for the hand shape + {} for the outcome",
+                hand_shape.name(),
+                hand_shape_score,
+                outcome_score,
+                width = name_width
+            )?;
+        }
+
+        std::fmt::Result::Ok(())
+    }
+}
+
+/// Returns the best-scoring [Round] that can be played against
+/// `opponent_hand_shape`.
+fn optimal_round<'a>(opponent_hand_shape: &HandShape<'a>) -> Round<'a> {
+    opponent_hand_shape
+        .game()
+        .shapes()
+        .map(|my_hand_shape| Round {
+            my_hand_shape,
+            opponent_hand_shape: *opponent_hand_shape,
+        })
+        .max_by_key(Scorable::score)
+        .unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy_guide_interpretation::StrategyGuideInterpretation;
+
+    /// Returns the [StrategyReport] of `encoded_strategy_guide`, read as hand
+    /// shapes in `game`.
+    fn gather(encoded_strategy_guide: &str, game: &'static HandGame) -> StrategyReport<'static> {
+        let strategy_guide = StrategyGuide::parse(
+            encoded_strategy_guide,
+            &StrategyGuideInterpretation::hand_shape(game),
+        )
+        .unwrap();
+
+        StrategyReport::gather(&strategy_guide, game)
+    }
+
+    #[test]
+    fn sample_report_is_correct() {
+        let report = gather("A Y\nB X\nC Z", HandGame::rock_paper_scissors());
+
+        assert_eq!(report.score(), 15);
+        assert_eq!(report.optimal_score, 24);
+        assert_eq!(report.expected_random_score, 15.0);
+        assert_eq!(
+            report.outcome_counts_by_opponent_hand_shape,
+            vec![[0, 0, 1], [1, 0, 0], [0, 1, 0]]
+        );
+        assert_eq!(report.scores_by_my_hand_shape, vec![(1, 0), (2, 6), (3, 3)]);
+    }
+
+    #[test]
+    fn optimal_score_picks_the_best_winning_hand_shape() {
+        // Both paper and Spock beat rock, but Spock scores more.
+        let report = gather("rock rock", HandGame::built_in("rpsls").unwrap());
+
+        assert_eq!(report.optimal_score, 4 + 6);
+    }
+}
@@ -0,0 +1,312 @@
+use std::fmt::Display;
+
+use crate::round::Round;
+use crate::round_outcome::RoundOutcome;
+use crate::scorable::Scorable;
+use crate::strategy_guide::StrategyGuide;
+
+/// Round-robin tournament where every strategy guide plays every other.
+///
+/// In each match, the two guides play their own hand shapes against each other
+/// round by round, ignoring whatever their guides expected the opponent to
+/// play. A match lasts as long as the shorter of the two guides, and the
+/// longer guide's leftover rounds go unplayed. Whichever guide scores more
+/// across the match wins it, and equal scores draw.
+#[derive(Debug)]
+pub(crate) struct Tournament<'a> {
+    /// Name and strategy guide of each entrant, in the order they were given.
+    entrants: Vec<(String, StrategyGuide<'a>)>,
+    /// Every match played, in the order they were played.
+    matches: Vec<Match>,
+}
+
+/// Result of one match of a [Tournament].
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct Match {
+    /// Position of each of the two entrants among those of the tournament.
+    pub(crate) entrants: (usize, usize),
+    /// How many times each [RoundOutcome] happened for the first entrant,
+    /// indexed by position in [RoundOutcome::ALL].
+    pub(crate) outcome_counts: [u32; 3],
+    /// How many rounds were played.
+    pub(crate) round_count: usize,
+    /// Score of each of the two entrants.
+    pub(crate) scores: (u32, u32),
+    /// How many rounds of the longer strategy guide went unplayed.
+    pub(crate) unplayed_round_count: usize,
+}
+
+impl Match {
+    /// Returns the [RoundOutcome] of this [Match] for the first entrant.
+    pub(crate) fn outcome(&self) -> RoundOutcome {
+        match self.scores.0.cmp(&self.scores.1) {
+            std::cmp::Ordering::Less => RoundOutcome::Loss,
+            std::cmp::Ordering::Equal => RoundOutcome::Draw,
+            std::cmp::Ordering::Greater => RoundOutcome::Win,
+        }
+    }
+}
+
+/// Tournament record of a single entrant.
+#[derive(Debug, Default, Eq, PartialEq)]
+pub(crate) struct Standing {
+    /// Position of the entrant among those of the tournament.
+    pub(crate) entrant: usize,
+    /// How many matches the entrant lost, drew, and won, indexed by position
+    /// in [RoundOutcome::ALL].
+    pub(crate) outcome_counts: [u32; 3],
+    /// Sum of the entrant's scores across every match.
+    pub(crate) score: u32,
+}
+
+impl Standing {
+    /// Returns the points the entrant earned: scored like a single round, so
+    /// 6 for each match won and 3 for each match drawn.
+    pub(crate) fn points(&self) -> u32 {
+        RoundOutcome::ALL
+            .iter()
+            .zip(self.outcome_counts)
+            .map(|(outcome, count)| outcome.score() * count)
+            .sum()
+    }
+}
+
+impl<'a> Tournament<'a> {
+    /// Plays a [Tournament] between every pair of the specified `entrants`,
+    /// each given as a name and a strategy guide.
+    pub(crate) fn play(entrants: Vec<(String, StrategyGuide<'a>)>) -> Tournament<'a> {
+        let mut matches = vec![];
+
+        for i in 0..entrants.len() {
+            for j in i + 1..entrants.len() {
+                let (first_rounds, second_rounds) =
+                    (entrants[i].1.rounds(), entrants[j].1.rounds());
+
+                let mut played_match = Match {
+                    entrants: (i, j),
+                    outcome_counts: [0; 3],
+                    round_count: first_rounds.len().min(second_rounds.len()),
+                    scores: (0, 0),
+                    unplayed_round_count: first_rounds.len().abs_diff(second_rounds.len()),
+                };
+
+                for (first_round, second_round) in first_rounds.iter().zip(second_rounds) {
+                    let round = Round {
+                        my_hand_shape: first_round.my_hand_shape,
+                        opponent_hand_shape: second_round.my_hand_shape,
+                    };
+                    let reversed_round = Round {
+                        my_hand_shape: second_round.my_hand_shape,
+                        opponent_hand_shape: first_round.my_hand_shape,
+                    };
+
+                    let outcome_index = RoundOutcome::ALL
+                        .iter()
+                        .position(|outcome| *outcome == round.outcome())
+                        .unwrap();
+
+                    played_match.outcome_counts[outcome_index] += 1;
+                    played_match.scores.0 += round.score();
+                    played_match.scores.1 += reversed_round.score();
+                }
+
+                matches.push(played_match);
+            }
+        }
+
+        Tournament { entrants, matches }
+    }
+
+    /// Returns every match played, in the order they were played.
+    pub(crate) fn matches(&self) -> &[Match] {
+        &self.matches
+    }
+
+    /// Returns the [Standing] of every entrant, best first.
+    ///
+    /// Entrants are ranked by points, then by total score, then in the order
+    /// they were given.
+    pub(crate) fn standings(&self) -> Vec<Standing> {
+        let mut standings = (0..self.entrants.len())
+            .map(|entrant| Standing {
+                entrant,
+                ..Standing::default()
+            })
+            .collect::<Vec<Standing>>();
+
+        for played_match in &self.matches {
+            let outcome = played_match.outcome();
+            let (first, second) = played_match.entrants;
+
+            for (entrant, outcome, score) in [
+                (first, outcome, played_match.scores.0),
+                (second, reversed(outcome), played_match.scores.1),
+            ] {
+                let outcome_index = RoundOutcome::ALL
+                    .iter()
+                    .position(|round_outcome| *round_outcome == outcome)
+                    .unwrap();
+
+                standings[entrant].outcome_counts[outcome_index] += 1;
+                standings[entrant].score += score;
+            }
+        }
+
+        standings.sort_by_key(|standing| {
+            (
+                std::cmp::Reverse(standing.points()),
+                std::cmp::Reverse(standing.score),
+                standing.entrant,
+            )
+        });
+
+        standings
+    }
+}
+
+impl Display for Tournament<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let name_width = self
+            .entrants
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or(0);
+
+        writeln!(f, "Standings:")?;
+        writeln!(
+            f,
+            "  #  {:<width$}  W  D  L  Pts  Score",
+            "Guide",
+            width = name_width
+        )?;
+        for (rank, standing) in self.standings().iter().enumerate() {
+            let [losses, draws, wins] = standing.outcome_counts;
+
+            writeln!(
+                f,
+                "{:>3}  {:<width$} {:>2} {:>2} {:>2} {:>4} {:>6}",
+                rank + 1,
+                self.entrants[standing.entrant].0,
+                wins,
+                draws,
+                losses,
+                standing.points(),
+                standing.score,
+                width = name_width
+            )?;
+        }
+
+        writeln!(f)?;
+        writeln!(f, "Matches:")?;
+        for played_match in self.matches() {
+            let (first, second) = played_match.entrants;
+            let [losses, draws, wins] = played_match.outcome_counts;
+
+            write!(
+                f,
+                "  {} vs {}: {}-{} ({} rounds: {} won, {} drawn, {} lost",
+                self.entrants[first].0,
+                self.entrants[second].0,
+                played_match.scores.0,
+                played_match.scores.1,
+                played_match.round_count,
+                wins,
+                draws,
+                losses
+            )?;
+            if played_match.unplayed_round_count > 0 {
+                write!(f, "; {} unplayed", played_match.unplayed_round_count)?;
+            }
+            writeln!(f, ")")?;
+        }
+
+        std::fmt::Result::Ok(())
+    }
+}
+
+/// Returns the [RoundOutcome] that the other player got when one got
+/// `outcome`.
+fn reversed(outcome: RoundOutcome) -> RoundOutcome {
+    match outcome {
+        RoundOutcome::Draw => RoundOutcome::Draw,
+        RoundOutcome::Loss => RoundOutcome::Win,
+        RoundOutcome::Win => RoundOutcome::Loss,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hand_game::HandGame;
+    use crate::strategy_guide_interpretation::StrategyGuideInterpretation;
+
+    /// Returns a [Tournament] between the strategy guides in
+    /// `encoded_strategy_guides`, read as rock paper scissors hand shapes.
+    fn play(encoded_strategy_guides: &[&str]) -> Tournament<'static> {
+        let interpretation =
+            StrategyGuideInterpretation::hand_shape(HandGame::rock_paper_scissors());
+
+        Tournament::play(
+            encoded_strategy_guides
+                .iter()
+                .enumerate()
+                .map(|(i, encoded_strategy_guide)| {
+                    (
+                        format!("guide {}", i + 1),
+                        StrategyGuide::parse(encoded_strategy_guide, &interpretation).unwrap(),
+                    )
+                })
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn every_pair_plays_once() {
+        let tournament = play(&["A X", "A Y", "A Z", "A X"]);
+
+        assert_eq!(tournament.matches().len(), 6);
+        assert_eq!(
+            tournament
+                .standings()
+                .iter()
+                .map(|standing| (standing.entrant, standing.points()))
+                .collect::<Vec<(usize, u32)>>(),
+            // Paper wins twice, and both rocks draw and score the same.
+            vec![(1, 12), (0, 9), (3, 9), (2, 6)]
+        );
+    }
+
+    #[test]
+    fn matches_last_as_long_as_the_shorter_guide() {
+        let tournament = play(&["A Y\nA Y\nA Y", "A X"]);
+
+        assert_eq!(
+            tournament.matches(),
+            [Match {
+                entrants: (0, 1),
+                outcome_counts: [0, 0, 1],
+                round_count: 1,
+                scores: (8, 1),
+                unplayed_round_count: 2,
+            }]
+        );
+    }
+
+    #[test]
+    fn matches_are_decided_by_score() {
+        // Rock beats scissors three times, but scissors score more for
+        // beating paper twice.
+        let tournament = play(&["A X\nA X\nA X\nA Y\nA Y", "A Z\nA Z\nA Z\nA Z\nA Z"]);
+        let played_match = &tournament.matches()[0];
+
+        assert_eq!(played_match.outcome_counts, [2, 0, 3]);
+        assert_eq!(played_match.scores, (3 * 7 + 2 * 2, 3 * 3 + 2 * 9));
+        assert_eq!(played_match.outcome(), RoundOutcome::Loss);
+
+        assert_eq!(
+            play(&["A X", "A X"]).matches()[0].outcome(),
+            RoundOutcome::Draw
+        );
+    }
+}
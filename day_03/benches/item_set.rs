@@ -0,0 +1,86 @@
+// Compares the bitmask item sets that rucksacks use against the hash sets they
+// used to, on every rucksack in the sample supplies list.
+
+// Modules are shared with the day, whose unit tests and other features go
+// unused here.
+#![allow(dead_code, unused_imports)]
+
+#[path = "../src/item_set.rs"]
+mod item_set;
+
+#[path = "../src/priorities.rs"]
+mod priorities;
+
+use std::collections::HashSet;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use item_set::ItemSet;
+use priorities::PriorityTable;
+
+/// Rucksacks to find collisions in, repeated for a more realistic workload.
+fn rucksacks() -> Vec<String> {
+    include_str!("../files/sample.txt")
+        .lines()
+        .cycle()
+        .take(300)
+        .map(String::from)
+        .collect()
+}
+
+/// Returns the priority total of the collisions in `rucksacks`, using hash
+/// sets.
+fn hash_set_priority_total(rucksacks: &[String], priority_table: &PriorityTable) -> u32 {
+    rucksacks
+        .iter()
+        .map(|rucksack| {
+            let item_types = rucksack.chars().collect::<Vec<char>>();
+            let (first, second) = item_types.split_at(item_types.len() / 2);
+
+            let first = first.iter().copied().collect::<HashSet<char>>();
+            let second = second.iter().copied().collect::<HashSet<char>>();
+
+            first
+                .intersection(&second)
+                .map(|&item_type| u32::from(priority_table.priority_of(item_type).unwrap()))
+                .sum::<u32>()
+        })
+        .sum()
+}
+
+/// Returns the priority total of the collisions in `rucksacks`, using
+/// [ItemSet]s.
+fn item_set_priority_total(rucksacks: &[String], priority_table: &PriorityTable) -> u32 {
+    rucksacks
+        .iter()
+        .map(|rucksack| {
+            let item_types = rucksack.chars().collect::<Vec<char>>();
+            let (first, second) = item_types.split_at(item_types.len() / 2);
+
+            let first = ItemSet::from_item_types(first.iter().copied(), priority_table).unwrap();
+            let second = ItemSet::from_item_types(second.iter().copied(), priority_table).unwrap();
+
+            (first & second).priority_total()
+        })
+        .sum()
+}
+
+fn compare_item_sets(c: &mut Criterion) {
+    let rucksacks = rucksacks();
+    let priority_table = PriorityTable::default();
+    assert_eq!(
+        hash_set_priority_total(&rucksacks, &priority_table),
+        item_set_priority_total(&rucksacks, &priority_table)
+    );
+
+    let mut group = c.benchmark_group("collision_priority_total");
+    group.bench_function("hash_set", |b| {
+        b.iter(|| hash_set_priority_total(black_box(&rucksacks), &priority_table))
+    });
+    group.bench_function("item_set", |b| {
+        b.iter(|| item_set_priority_total(black_box(&rucksacks), &priority_table))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, compare_item_sets);
+criterion_main!(benches);
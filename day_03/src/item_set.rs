@@ -0,0 +1,126 @@
+use std::fmt::Debug;
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign};
+
+use anyhow::{anyhow, Result};
+
+use crate::priorities::PriorityTable;
+
+/// Set of item types, stored as a bitmask where bit `n` is set if the item
+/// type with priority `n` is in the set.
+///
+/// Intersecting and uniting sets is a single bitwise operation with `&` and
+/// `|`.
+#[derive(Clone, Copy, Default, Eq, PartialEq)]
+pub(crate) struct ItemSet(u64);
+
+impl ItemSet {
+    /// Returns the [ItemSet] of every one of the specified `item_types`.
+    ///
+    /// * `priority_table` decides which item types are valid, and their
+    ///   priorities
+    pub(crate) fn from_item_types(
+        item_types: impl IntoIterator<Item = char>,
+        priority_table: &PriorityTable,
+    ) -> Result<ItemSet> {
+        item_types
+            .into_iter()
+            .try_fold(ItemSet::default(), |item_set, item_type| {
+                let priority = priority_table
+                    .priority_of(item_type)
+                    .ok_or_else(|| anyhow!("{:?} is not a valid item type", item_type))?;
+
+                Ok(item_set | ItemSet(1 << priority))
+            })
+    }
+
+    /// Returns the priority of every item type in this [ItemSet], lowest
+    /// first.
+    pub(crate) fn priorities(&self) -> impl Iterator<Item = u8> {
+        let bits = self.0;
+
+        (1..=PriorityTable::MAX_PRIORITY).filter(move |priority| bits & (1 << priority) != 0)
+    }
+
+    /// Returns every item type in this [ItemSet], lowest priority first.
+    ///
+    /// * `priority_table` must be the one this [ItemSet] was built with
+    pub(crate) fn item_types<'a>(
+        &self,
+        priority_table: &'a PriorityTable,
+    ) -> impl Iterator<Item = char> + 'a {
+        self.priorities()
+            .map(|priority| priority_table.item_type_of(priority).unwrap())
+    }
+
+    /// Returns the sum of the priorities of every item type in this
+    /// [ItemSet].
+    pub(crate) fn priority_total(&self) -> u32 {
+        self.priorities().map(u32::from).sum()
+    }
+}
+
+impl BitAnd for ItemSet {
+    type Output = ItemSet;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        ItemSet(self.0 & rhs.0)
+    }
+}
+
+impl BitAndAssign for ItemSet {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl BitOr for ItemSet {
+    type Output = ItemSet;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        ItemSet(self.0 | rhs.0)
+    }
+}
+
+impl BitOrAssign for ItemSet {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl Debug for ItemSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_set().entries(self.priorities()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_operations_work() {
+        let priority_table = PriorityTable::default();
+        let first = ItemSet::from_item_types("vJrwpWtwJgWr".chars(), &priority_table).unwrap();
+        let second = ItemSet::from_item_types("hcsFMMfFFhFp".chars(), &priority_table).unwrap();
+
+        assert_eq!(
+            (first & second)
+                .item_types(&priority_table)
+                .collect::<String>(),
+            "p"
+        );
+        assert_eq!((first & second).priority_total(), 16);
+        assert_eq!((first | second).priorities().count(), 14);
+        assert_eq!(first & ItemSet::default(), ItemSet::default());
+    }
+
+    #[test]
+    fn parse_rejects_unknown_item_types() {
+        assert_eq!(
+            ItemSet::from_item_types("ab1".chars(), &PriorityTable::default())
+                .unwrap_err()
+                .to_string(),
+            "'1' is not a valid item type"
+        );
+    }
+}
@@ -1,57 +1,155 @@
 extern crate advent;
 extern crate anyhow;
-extern crate lazy_static;
+extern crate clap;
 extern crate regex;
 extern crate tokio;
 
+mod item_set;
 mod priorities;
+mod repair_plan;
 mod rucksack;
 mod rucksack_group;
 
-use anyhow::{Context, Result};
-use priorities::priority_of;
-use rucksack::Rucksack;
+use advent::{Config, Solution};
+use anyhow::{anyhow, Result};
+use clap::Parser;
+use priorities::PriorityTable;
+use repair_plan::RepairPlan;
+use rucksack::{parse_rucksacks, Rucksack};
 use rucksack_group::RucksackGroup;
 
+/// Command line arguments for the "Rucksack Reorganization" puzzle.
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(flatten)]
+    config: Config,
+
+    /// Specifies how many equally sized compartments each rucksack has.
+    #[arg(default_value_t = 2, long)]
+    compartments: usize,
+
+    /// Specifies how many rucksacks each group of elves carries.
+    #[arg(default_value_t = 3, long)]
+    group_size: usize,
+
+    /// Lists every valid item type in priority order, as single characters or
+    /// runs like `a-z`.
+    #[arg(default_value = "a-zA-Z", long)]
+    alphabet: PriorityTable,
+
+    /// Prints which items to move to resolve every rucksack's collisions, and
+    /// which groups lack a unique badge, instead of printing the answer.
+    #[arg(long)]
+    plan: bool,
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
-    let config = advent::begin();
+    let args = advent::begin_with_args::<Args>();
+    let config = args.config;
 
     let supplies_list = advent::data(&config).await?;
 
-    let rucksacks = supplies_list
-        .lines()
-        .map(|encoded_rucksack| Rucksack::parse(encoded_rucksack))
-        .collect::<Result<Vec<Rucksack>>>()
-        .context("Failed to read supplies list")?;
-
-    let all_collisions = match config.part {
-        1 => rucksacks
-            .iter()
-            .flat_map(|rucksack| rucksack.collisions.iter())
-            .map(|collision_char| collision_char.to_owned())
-            .collect::<Vec<char>>(),
-        2 => {
-            let rucksack_groups = rucksacks
-                .chunks(3)
-                .map(|rucksacks| RucksackGroup::new(rucksacks.iter()))
-                .collect::<Vec<RucksackGroup>>();
-
-            rucksack_groups
-                .iter()
-                .flat_map(|x| x.collisions.iter())
-                .map(|collision_char| collision_char.to_owned())
-                .collect::<Vec<char>>()
-        }
-        _ => todo!(),
+    let rucksack_reorganization = RucksackReorganization {
+        compartment_count: args.compartments,
+        group_size: args.group_size,
+        priority_table: args.alphabet,
     };
 
-    let priority_total = all_collisions
-        .iter()
-        .map(|collision| priority_of(collision).unwrap_or(&0).to_owned())
-        .fold(0, |acc: u32, priority| acc + u32::from(priority));
+    if args.plan {
+        let rucksacks = rucksack_reorganization.parse_rucksacks(&supplies_list)?;
+        let rucksack_groups = rucksack_reorganization.group(&rucksacks)?;
 
-    println!("Priority total: {}", priority_total);
+        print!(
+            "{}",
+            RepairPlan::new(
+                &rucksacks,
+                &rucksack_groups,
+                rucksack_reorganization.group_size,
+                &rucksack_reorganization.priority_table
+            )
+        );
+
+        return Ok(());
+    }
+
+    let answer = rucksack_reorganization.solve(config.part, &supplies_list)?;
+
+    println!("Priority total: {}", answer);
 
     Ok(())
 }
+
+/// Solution to the "Rucksack Reorganization" puzzle.
+struct RucksackReorganization {
+    /// How many equally sized compartments each rucksack has.
+    compartment_count: usize,
+    /// How many rucksacks each group of elves carries.
+    group_size: usize,
+    /// Priority of every valid item type.
+    priority_table: PriorityTable,
+}
+
+impl Default for RucksackReorganization {
+    fn default() -> Self {
+        RucksackReorganization {
+            compartment_count: 2,
+            group_size: 3,
+            priority_table: PriorityTable::default(),
+        }
+    }
+}
+
+impl RucksackReorganization {
+    /// Returns every [Rucksack] in `supplies_list`.
+    fn parse_rucksacks(&self, supplies_list: &str) -> Result<Vec<Rucksack>> {
+        parse_rucksacks(supplies_list, self.compartment_count, &self.priority_table)
+    }
+
+    /// Returns `rucksacks` split into consecutive groups.
+    fn group<'a>(&self, rucksacks: &'a [Rucksack]) -> Result<Vec<RucksackGroup<'a>>> {
+        if self.group_size == 0 {
+            return Err(anyhow!("Groups need at least one rucksack"));
+        }
+        if !rucksacks.len().is_multiple_of(self.group_size) {
+            return Err(anyhow!(
+                "The last group, starting on line {}, has {} rucksacks instead of {}",
+                rucksacks.len() - rucksacks.len() % self.group_size + 1,
+                rucksacks.len() % self.group_size,
+                self.group_size
+            ));
+        }
+
+        Ok(rucksacks
+            .chunks(self.group_size)
+            .map(|rucksacks| RucksackGroup::new(rucksacks.iter()))
+            .collect())
+    }
+}
+
+impl Solution for RucksackReorganization {
+    fn solve(&self, part: u8, supplies_list: &str) -> Result<String> {
+        let rucksacks = self.parse_rucksacks(supplies_list)?;
+
+        let priority_total = match part {
+            1 => rucksacks
+                .iter()
+                .map(|rucksack| rucksack.collisions.priority_total())
+                .sum::<u32>(),
+            2 => self
+                .group(&rucksacks)?
+                .iter()
+                .map(|rucksack_group| rucksack_group.collisions.priority_total())
+                .sum::<u32>(),
+            _ => return Err(anyhow!("Part {} is not implemented", part)),
+        };
+
+        Ok(priority_total.to_string())
+    }
+}
+
+advent::sample_tests!(RucksackReorganization::default());
+advent::reference_tests!(
+    RucksackReorganization::default(),
+    advent::generators::rucksacks()
+);
@@ -1,37 +1,176 @@
-use std::collections::HashMap;
+use std::fmt::Display;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
 
-use lazy_static::lazy_static;
+use anyhow::{anyhow, Error, Result};
 
-lazy_static! {
-  /// Mapping from certain characters ([a-zA-Z]) to priority integers.
-  static ref PRIORITY_BY_ITEM_TYPE: HashMap<char, u8> =
-      index_priorities_by_item_type();
+/// Table assigning a priority to every valid item type.
+///
+/// Item types are listed as runs of consecutive characters, so that
+/// priorities can be computed directly from a character's offset within its
+/// run. The first item type listed has priority 1, and each one after it has
+/// the next priority.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct PriorityTable {
+    /// Every run of item types, with the priority of its first item type.
+    runs: Vec<(RangeInclusive<char>, u8)>,
 }
 
-/// Returns the priority of the specified `item_type` character.
-pub(crate) fn priority_of(item_type: &char) -> Option<&u8> {
-    PRIORITY_BY_ITEM_TYPE.get(item_type)
+impl PriorityTable {
+    /// Highest priority that a [PriorityTable] can assign, so that every item
+    /// type fits in an [crate::item_set::ItemSet].
+    pub(crate) const MAX_PRIORITY: u8 = 63;
+
+    /// Creates and returns a new [PriorityTable] listing each of `runs` in
+    /// order.
+    pub(crate) fn new(runs: Vec<RangeInclusive<char>>) -> Result<PriorityTable> {
+        let mut table = PriorityTable { runs: vec![] };
+        let mut next_priority = 1u32;
+
+        for run in runs {
+            if run.is_empty() {
+                return Err(anyhow!(
+                    "{:?}-{:?} is not a valid run of item types",
+                    run.start(),
+                    run.end()
+                ));
+            }
+            if let Some(item_type) = run
+                .clone()
+                .find(|&item_type| table.priority_of(item_type).is_some())
+            {
+                return Err(anyhow!("{:?} is listed more than once", item_type));
+            }
+
+            let run_length = *run.end() as u32 - *run.start() as u32 + 1;
+            if next_priority + run_length - 1 > u32::from(PriorityTable::MAX_PRIORITY) {
+                return Err(anyhow!(
+                    "At most {} item types can be listed",
+                    PriorityTable::MAX_PRIORITY
+                ));
+            }
+
+            table.runs.push((run, next_priority as u8));
+            next_priority += run_length;
+        }
+
+        Ok(table)
+    }
+
+    /// Returns the priority of the specified `item_type` character, if it is
+    /// a valid item type.
+    pub(crate) fn priority_of(&self, item_type: char) -> Option<u8> {
+        self.runs
+            .iter()
+            .find(|(run, _)| run.contains(&item_type))
+            .map(|(run, first_priority)| {
+                first_priority + (item_type as u32 - *run.start() as u32) as u8
+            })
+    }
+
+    /// Returns the item type character with the specified `priority`, if there
+    /// is one.
+    pub(crate) fn item_type_of(&self, priority: u8) -> Option<char> {
+        self.runs
+            .iter()
+            .rev()
+            .find(|(_, first_priority)| *first_priority <= priority)
+            .and_then(|(run, first_priority)| {
+                char::from_u32(*run.start() as u32 + u32::from(priority - first_priority))
+                    .filter(|item_type| run.contains(item_type))
+            })
+    }
+}
+
+impl Default for PriorityTable {
+    /// Returns the [PriorityTable] of the puzzle: 1 through 26 for `a` through
+    /// `z`, and 27 through 52 for `A` through `Z`.
+    fn default() -> Self {
+        PriorityTable::new(vec!['a'..='z', 'A'..='Z']).unwrap()
+    }
+}
+
+impl Display for PriorityTable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (run, _) in &self.runs {
+            if run.start() == run.end() {
+                write!(f, "{}", run.start())?;
+            } else {
+                write!(f, "{}-{}", run.start(), run.end())?;
+            }
+        }
+
+        std::fmt::Result::Ok(())
+    }
+}
+
+impl FromStr for PriorityTable {
+    type Err = Error;
+
+    /// Returns the [PriorityTable] listing the item types in `s`, which are
+    /// either single characters or runs like `a-z`, in priority order.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let item_types = s.chars().collect::<Vec<char>>();
+
+        let mut runs = vec![];
+        let mut i = 0;
+        while i < item_types.len() {
+            if i + 2 < item_types.len() && item_types[i + 1] == '-' {
+                runs.push(item_types[i]..=item_types[i + 2]);
+                i += 3;
+            } else {
+                runs.push(item_types[i]..=item_types[i]);
+                i += 1;
+            }
+        }
+
+        PriorityTable::new(runs)
+    }
 }
 
-/// Generates [PRIORITY_BY_ITEM_TYPE].
-fn index_priorities_by_item_type() -> HashMap<char, u8> {
-    let mut priority_by_item_type = HashMap::new();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    let mut priority = 1;
+    #[test]
+    fn default_priorities_round_trip() {
+        let table = PriorityTable::default();
 
-    for code_point in b'a'..=b'z' {
-        let item_type = char::from(code_point);
+        assert_eq!(table.priority_of('a'), Some(1));
+        assert_eq!(table.priority_of('z'), Some(26));
+        assert_eq!(table.priority_of('A'), Some(27));
+        assert_eq!(table.priority_of('Z'), Some(52));
+        assert_eq!(table.priority_of('1'), None);
 
-        priority_by_item_type.insert(item_type, priority);
-        priority += 1;
+        for priority in 1..=52 {
+            assert_eq!(
+                table.priority_of(table.item_type_of(priority).unwrap()),
+                Some(priority)
+            );
+        }
+        assert_eq!(table.item_type_of(0), None);
+        assert_eq!(table.item_type_of(53), None);
     }
 
-    for code_point in b'A'..=b'Z' {
-        let item_type = char::from(code_point);
+    #[test]
+    fn from_str_reads_runs_and_single_item_types() {
+        let table = "0-9α-ω_".parse::<PriorityTable>().unwrap();
 
-        priority_by_item_type.insert(item_type, priority);
-        priority += 1;
+        assert_eq!(table.priority_of('0'), Some(1));
+        assert_eq!(table.priority_of('α'), Some(11));
+        assert_eq!(table.priority_of('_'), Some(36));
+        assert_eq!(table.priority_of('β'), Some(12));
+        assert_eq!(table.to_string(), "0-9α-ω_");
+        assert_eq!(
+            "a-zA-Z".parse::<PriorityTable>().unwrap(),
+            PriorityTable::default()
+        );
     }
 
-    priority_by_item_type
+    #[test]
+    fn from_str_rejects_invalid_tables() {
+        assert!("a-zb".parse::<PriorityTable>().is_err());
+        assert!("z-a".parse::<PriorityTable>().is_err());
+        assert!("a-zA-Z0-9!-#".parse::<PriorityTable>().is_err());
+    }
 }
@@ -0,0 +1,156 @@
+use std::fmt::Display;
+
+use crate::priorities::PriorityTable;
+use crate::rucksack::{ItemMove, Rucksack};
+use crate::rucksack_group::RucksackGroup;
+
+/// Report of what it would take to put every rucksack in order: which items to
+/// move so that no item type is in more than one compartment, and which
+/// groups do not have exactly one badge candidate.
+#[derive(Debug)]
+pub(crate) struct RepairPlan {
+    /// Position of each group without exactly one badge candidate, along with
+    /// its candidates.
+    badge_problems: Vec<(usize, Vec<char>)>,
+    /// How many rucksacks are in each group.
+    group_size: usize,
+    /// Position of each rucksack with collisions, along with the moves that
+    /// resolve them.
+    rucksack_repairs: Vec<(usize, Vec<ItemMove>)>,
+}
+
+impl RepairPlan {
+    /// Returns the [RepairPlan] for `rucksacks`, grouped into
+    /// `rucksack_groups` of `group_size` each.
+    ///
+    /// * `priority_table` must be the one the rucksacks were parsed with
+    pub(crate) fn new(
+        rucksacks: &[Rucksack],
+        rucksack_groups: &[RucksackGroup],
+        group_size: usize,
+        priority_table: &PriorityTable,
+    ) -> RepairPlan {
+        RepairPlan {
+            badge_problems: rucksack_groups
+                .iter()
+                .enumerate()
+                .map(|(i, rucksack_group)| (i, rucksack_group.badge_candidates(priority_table)))
+                .filter(|(_, badge_candidates)| badge_candidates.len() != 1)
+                .collect(),
+            group_size,
+            rucksack_repairs: rucksacks
+                .iter()
+                .enumerate()
+                .map(|(i, rucksack)| (i, rucksack.repair_moves(priority_table)))
+                .filter(|(_, item_moves)| !item_moves.is_empty())
+                .collect(),
+        }
+    }
+
+    /// Returns the total number of items that need to move.
+    pub(crate) fn item_count(&self) -> usize {
+        self.rucksack_repairs
+            .iter()
+            .flat_map(|(_, item_moves)| item_moves)
+            .map(ItemMove::item_count)
+            .sum()
+    }
+}
+
+impl Display for RepairPlan {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(
+            f,
+            "{} items to move across {} rucksacks",
+            self.item_count(),
+            self.rucksack_repairs.len()
+        )?;
+
+        for (i, item_moves) in &self.rucksack_repairs {
+            writeln!(f)?;
+            writeln!(
+                f,
+                "Rucksack on line {}: move {} items",
+                i + 1,
+                item_moves.iter().map(ItemMove::item_count).sum::<usize>()
+            )?;
+
+            for item_move in item_moves {
+                let sources = item_move
+                    .sources
+                    .iter()
+                    .map(|(source, count)| format!("{} from compartment {}", count, source + 1))
+                    .collect::<Vec<String>>();
+
+                writeln!(
+                    f,
+                    "  {}: move {} to compartment {}",
+                    item_move.item_type,
+                    sources.join(", "),
+                    item_move.destination + 1
+                )?;
+            }
+        }
+
+        writeln!(f)?;
+        writeln!(
+            f,
+            "{} groups without exactly one badge candidate",
+            self.badge_problems.len()
+        )?;
+
+        for (i, badge_candidates) in &self.badge_problems {
+            let first_line_number = i * self.group_size + 1;
+            let last_line_number = first_line_number + self.group_size - 1;
+
+            match badge_candidates.len() {
+                0 => writeln!(
+                    f,
+                    "  Group on lines {}-{}: no badge candidates",
+                    first_line_number, last_line_number
+                )?,
+                _ => writeln!(
+                    f,
+                    "  Group on lines {}-{}: {} badge candidates ({})",
+                    first_line_number,
+                    last_line_number,
+                    badge_candidates.len(),
+                    badge_candidates.iter().collect::<String>()
+                )?,
+            }
+        }
+
+        std::fmt::Result::Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plan_finds_collisions_and_badge_problems() {
+        let priority_table = PriorityTable::default();
+
+        let rucksacks = ["abca", "xyzw", "abxb", "bxbx"]
+            .iter()
+            .map(|encoded_rucksack| Rucksack::parse(encoded_rucksack, 2, &priority_table).unwrap())
+            .collect::<Vec<Rucksack>>();
+        let rucksack_groups = rucksacks
+            .chunks(2)
+            .map(|rucksacks| RucksackGroup::new(rucksacks.iter()))
+            .collect::<Vec<RucksackGroup>>();
+
+        let plan = RepairPlan::new(&rucksacks, &rucksack_groups, 2, &priority_table);
+
+        assert_eq!(
+            plan.rucksack_repairs
+                .iter()
+                .map(|(i, item_moves)| (*i, item_moves.len()))
+                .collect::<Vec<(usize, usize)>>(),
+            [(0, 1), (2, 1), (3, 2)]
+        );
+        assert_eq!(plan.item_count(), 1 + 1 + 2);
+        assert_eq!(plan.badge_problems, [(0, vec![]), (1, vec!['b', 'x'])]);
+    }
+}
@@ -1,56 +1,202 @@
-use std::collections::HashSet;
+use anyhow::{anyhow, Context, Result};
 
-use anyhow::{anyhow, Result};
+use crate::item_set::ItemSet;
+use crate::priorities::PriorityTable;
 
 /// Represents one rucksack containing supplies for the jungle journey.
 #[derive(Debug)]
 pub(crate) struct Rucksack {
     /// Set of all the item types shared by any compartments.
-    pub(crate) collisions: HashSet<char>,
+    pub(crate) collisions: ItemSet,
+    /// Items in each compartment, in order.
+    pub(crate) compartments: Vec<Vec<char>>,
     /// Set of all the item types to appear in this [Rucksack].
-    pub(crate) item_types: HashSet<char>,
+    pub(crate) item_types: ItemSet,
+}
+
+/// Moves that gather every item of one type into a single compartment.
+#[derive(Debug, Eq, PartialEq)]
+pub(crate) struct ItemMove {
+    /// Position of the compartment that the items move to.
+    pub(crate) destination: usize,
+    /// Item type being moved.
+    pub(crate) item_type: char,
+    /// Position of each compartment that items move out of, with how many.
+    pub(crate) sources: Vec<(usize, usize)>,
+}
+
+impl ItemMove {
+    /// Returns how many items this [ItemMove] moves.
+    pub(crate) fn item_count(&self) -> usize {
+        self.sources.iter().map(|(_, count)| count).sum()
+    }
 }
 
 impl Rucksack {
     /// Compiles a new [Rucksack] from its encoded form.
-    pub(crate) fn parse(encoded_rucksack: &str) -> Result<Rucksack> {
+    ///
+    /// * `compartment_count` is how many equally sized compartments the items
+    ///   of the rucksack are split into, in order
+    /// * `priority_table` decides which item types are valid
+    pub(crate) fn parse(
+        encoded_rucksack: &str,
+        compartment_count: usize,
+        priority_table: &PriorityTable,
+    ) -> Result<Rucksack> {
         let item_types = encoded_rucksack.chars().collect::<Vec<char>>();
 
-        if item_types.len() < 2 {
+        if compartment_count == 0 {
+            return Err(anyhow!("A rucksack needs at least one compartment"));
+        }
+        if item_types.len() < compartment_count {
             return Err(anyhow!(
                 "\"{}\" is not a valid rucksack: it is not long enough",
                 encoded_rucksack
             ));
         }
-        if item_types.len() % 2 == 1 {
+        if !item_types.len().is_multiple_of(compartment_count) {
             return Err(anyhow!(
-                "\"{}\" is not a valid rucksack: it is not even length string",
-                encoded_rucksack
+                "\"{}\" is not a valid rucksack: its {} items cannot be split into {} equal compartments",
+                encoded_rucksack,
+                item_types.len(),
+                compartment_count
             ));
         }
 
-        let halfway = item_types.len() / 2;
-
-        let first_compartment = item_types[0..halfway]
-            .iter()
-            .map(|item_type| item_type.to_owned())
-            .collect::<HashSet<char>>();
-        let second_compartment = item_types[halfway..]
-            .iter()
-            .map(|item_type| item_type.to_owned())
-            .collect::<HashSet<char>>();
-
-        let collisions = first_compartment
-            .intersection(&second_compartment)
-            .map(|collision| collision.to_owned())
-            .collect::<HashSet<char>>();
-
-        Ok(Rucksack {
-            collisions: collisions,
-            item_types: item_types
-                .iter()
-                .map(|item_type| item_type.to_owned())
-                .collect::<HashSet<char>>(),
+        let mut rucksack = Rucksack {
+            collisions: ItemSet::default(),
+            compartments: item_types
+                .chunks(item_types.len() / compartment_count)
+                .map(|compartment| compartment.to_vec())
+                .collect(),
+            item_types: ItemSet::default(),
+        };
+
+        for compartment in &rucksack.compartments {
+            let compartment = ItemSet::from_item_types(compartment.iter().copied(), priority_table)
+                .with_context(|| format!("\"{}\" is not a valid rucksack", encoded_rucksack))?;
+
+            rucksack.collisions |= rucksack.item_types & compartment;
+            rucksack.item_types |= compartment;
+        }
+
+        Ok(rucksack)
+    }
+
+    /// Returns the fewest [ItemMove]s that leave no item type in more than
+    /// one compartment, one for each collision.
+    ///
+    /// Each item type stays in whichever compartment has the most of it
+    /// (the first such compartment, in case of a tie), and every other item
+    /// of that type moves there. Item types are independent of each other, so
+    /// no other plan takes fewer moves.
+    ///
+    /// * `priority_table` must be the one this [Rucksack] was parsed with
+    pub(crate) fn repair_moves(&self, priority_table: &PriorityTable) -> Vec<ItemMove> {
+        self.collisions
+            .item_types(priority_table)
+            .map(|item_type| {
+                let counts = self
+                    .compartments
+                    .iter()
+                    .map(|compartment| {
+                        compartment
+                            .iter()
+                            .filter(|&&other_item_type| other_item_type == item_type)
+                            .count()
+                    })
+                    .collect::<Vec<usize>>();
+
+                // Reversed so that the first compartment wins ties.
+                let destination = (0..counts.len()).rev().max_by_key(|&i| counts[i]).unwrap();
+
+                ItemMove {
+                    destination,
+                    item_type,
+                    sources: counts
+                        .iter()
+                        .enumerate()
+                        .filter(|&(i, &count)| i != destination && count > 0)
+                        .map(|(i, &count)| (i, count))
+                        .collect(),
+                }
+            })
+            .collect()
+    }
+}
+
+/// Returns every [Rucksack] in `supplies_list`, one per line.
+///
+/// * `compartment_count` is how many equally sized compartments the items of
+///   each rucksack are split into
+/// * `priority_table` decides which item types are valid
+pub(crate) fn parse_rucksacks(
+    supplies_list: &str,
+    compartment_count: usize,
+    priority_table: &PriorityTable,
+) -> Result<Vec<Rucksack>> {
+    supplies_list
+        .lines()
+        .enumerate()
+        .map(|(i, encoded_rucksack)| {
+            Rucksack::parse(encoded_rucksack, compartment_count, priority_table)
+                .with_context(|| format!("Line {} of the supplies list is invalid", i + 1))
         })
+        .collect::<Result<Vec<Rucksack>>>()
+        .context("Failed to read supplies list")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collisions_span_any_two_compartments() {
+        let priority_table = PriorityTable::default();
+
+        let rucksack = Rucksack::parse("abcbcdxyzx", 2, &priority_table).unwrap();
+        assert_eq!(rucksack.collisions.priority_total(), 0);
+
+        let rucksack = Rucksack::parse("abcbcdxyzx", 5, &priority_table).unwrap();
+        assert_eq!(
+            rucksack.collisions.priorities().collect::<Vec<u8>>(),
+            [2, 3, 24]
+        );
+    }
+
+    #[test]
+    fn repair_moves_gather_items_where_most_of_them_are() {
+        let priority_table = PriorityTable::default();
+
+        let rucksack = Rucksack::parse("aabbcaab", 2, &priority_table).unwrap();
+        assert_eq!(
+            rucksack.repair_moves(&priority_table),
+            [
+                ItemMove {
+                    destination: 0,
+                    item_type: 'a',
+                    sources: vec![(1, 2)],
+                },
+                ItemMove {
+                    destination: 0,
+                    item_type: 'b',
+                    sources: vec![(1, 1)],
+                },
+            ]
+        );
+        assert!(Rucksack::parse("abcd", 2, &priority_table)
+            .unwrap()
+            .repair_moves(&priority_table)
+            .is_empty());
+    }
+
+    #[test]
+    fn parse_explains_misshapen_rucksacks() {
+        let error = Rucksack::parse("abcd", 3, &PriorityTable::default()).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "\"abcd\" is not a valid rucksack: its 4 items cannot be split into 3 equal compartments"
+        );
     }
 }
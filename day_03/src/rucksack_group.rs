@@ -1,12 +1,12 @@
-use std::collections::HashSet;
-
+use crate::item_set::ItemSet;
+use crate::priorities::PriorityTable;
 use crate::rucksack::Rucksack;
 
 /// Represents a group of three rucksacks.
 #[derive(Debug)]
 pub(crate) struct RucksackGroup<'a> {
     /// Set of all the item types shared by all of the rucksacks.
-    pub(crate) collisions: HashSet<char>,
+    pub(crate) collisions: ItemSet,
     /// All of the rucksacks in this group.
     #[allow(dead_code)]
     pub(crate) rucksacks: Vec<&'a Rucksack>,
@@ -15,30 +15,25 @@ pub(crate) struct RucksackGroup<'a> {
 impl<'a> RucksackGroup<'a> {
     /// Assembles a new [RucksackGroup] from a specified collection of `rucksacks`.
     pub(crate) fn new(rucksacks: impl Iterator<Item = &'a Rucksack>) -> RucksackGroup<'a> {
-        let cloned_rucksacks = rucksacks.collect::<Vec<&'a Rucksack>>();
+        let rucksacks = rucksacks.collect::<Vec<&'a Rucksack>>();
 
-        let collisions = cloned_rucksacks
+        let collisions = rucksacks
             .iter()
-            .fold(None, |maybe_acc: Option<HashSet<char>>, rucksack| {
-                let item_types = rucksack
-                    .item_types
-                    .iter()
-                    .map(|item_type| item_type.to_owned())
-                    .collect(); // horrible
-
-                maybe_acc
-                    .map(|acc| {
-                        acc.intersection(&item_types)
-                            .map(|item_type| item_type.to_owned())
-                            .collect()
-                    })
-                    .or(Some(item_types))
-            })
-            .unwrap_or(HashSet::new());
+            .map(|rucksack| rucksack.item_types)
+            .reduce(|acc, item_types| acc & item_types)
+            .unwrap_or_default();
 
         RucksackGroup {
-            collisions: collisions,
-            rucksacks: cloned_rucksacks,
+            collisions,
+            rucksacks,
         }
     }
+
+    /// Returns every item type that could be this group's badge, because every
+    /// rucksack in the group has it.
+    ///
+    /// * `priority_table` must be the one the rucksacks were parsed with
+    pub(crate) fn badge_candidates(&self, priority_table: &PriorityTable) -> Vec<char> {
+        self.collisions.item_types(priority_table).collect()
+    }
 }
@@ -0,0 +1,77 @@
+// Compares finding overlapping section assignments with an [OverlapIndex]
+// against scanning every assignment with `overlaps`, on a large synthetic camp.
+
+// Modules are shared with the day, whose unit tests and other features go
+// unused here.
+#![allow(dead_code, unused_imports)]
+
+#[path = "../src/overlap_index.rs"]
+mod overlap_index;
+
+#[path = "../src/section_assignment.rs"]
+mod section_assignment;
+
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use overlap_index::OverlapIndex;
+use section_assignment::SectionAssignment;
+
+/// How many section assignments the synthetic camp has.
+const SECTION_ASSIGNMENT_COUNT: usize = 1_000_000;
+
+/// How many sections the synthetic camp spans.
+const SECTION_COUNT: u32 = 100_000_000;
+
+/// Returns the section assignments of a synthetic camp, each spanning up to a
+/// thousand sections, generated with a fixed xorshift sequence so that every
+/// run measures the same camp.
+fn section_assignments() -> Vec<SectionAssignment> {
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let mut next = move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    };
+
+    (0..SECTION_ASSIGNMENT_COUNT)
+        .map(|_| {
+            let from = (next() % u64::from(SECTION_COUNT)) as u32;
+            let to = from + (next() % 1_000) as u32;
+
+            SectionAssignment::parse(&format!("{}-{}", from, to)).unwrap()
+        })
+        .collect()
+}
+
+/// Returns the position of every one of `section_assignments` that overlaps
+/// `query`, by checking each of them.
+fn linear_overlapping(
+    section_assignments: &[SectionAssignment],
+    query: &SectionAssignment,
+) -> Vec<usize> {
+    (0..section_assignments.len())
+        .filter(|&i| section_assignments[i].overlaps(query))
+        .collect()
+}
+
+fn compare_overlap_queries(c: &mut Criterion) {
+    let section_assignments = section_assignments();
+    let overlap_index = OverlapIndex::new(&section_assignments);
+    let query = SectionAssignment::parse("50000000-50010000").unwrap();
+    assert_eq!(
+        linear_overlapping(&section_assignments, &query),
+        overlap_index.overlapping(&query)
+    );
+
+    let mut group = c.benchmark_group("overlapping_section_assignments");
+    group.bench_function("linear_scan", |b| {
+        b.iter(|| linear_overlapping(black_box(&section_assignments), black_box(&query)))
+    });
+    group.bench_function("overlap_index", |b| {
+        b.iter(|| black_box(&overlap_index).overlapping(black_box(&query)))
+    });
+    group.finish();
+}
+
+criterion_group!(benches, compare_overlap_queries);
+criterion_main!(benches);
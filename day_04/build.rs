@@ -0,0 +1,5 @@
+//! Generates a test for each sample in `files/`, for `advent::sample_tests!`.
+
+fn main() -> std::io::Result<()> {
+    advent::testing::generate_sample_tests()
+}
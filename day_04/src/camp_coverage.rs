@@ -0,0 +1,299 @@
+use std::fmt::Display;
+
+use advent::interval::{Interval, IntervalSet};
+
+use crate::section_assignment::SectionAssignment;
+
+/// Camp-wide summary of how many elves cover each section.
+#[derive(Debug)]
+pub(crate) struct CampCoverage {
+    /// Every run of sections covered by the same number of elves, from the
+    /// first section anybody covers to the last, with that number.
+    coverage_runs: Vec<(Interval<u32>, usize)>,
+    /// Sections covered by more than this many elves are crowded.
+    crowding_threshold: usize,
+    /// Line number and section assignment of every elf, in order.
+    elves: Vec<(usize, SectionAssignment)>,
+    /// Position of each elf in the smallest group of elves that covers the
+    /// same sections as all of them, in order.
+    essential_elves: Vec<usize>,
+}
+
+impl CampCoverage {
+    /// Returns the [CampCoverage] of the specified `elves`, each given as a
+    /// line number and a section assignment.
+    ///
+    /// * `crowding_threshold` is how many elves can cover a section before it
+    ///   is crowded
+    pub(crate) fn gather(
+        elves: Vec<(usize, SectionAssignment)>,
+        crowding_threshold: usize,
+    ) -> CampCoverage {
+        CampCoverage {
+            coverage_runs: sweep(&elves),
+            crowding_threshold,
+            essential_elves: essential_elves(&elves),
+            elves,
+        }
+    }
+
+    /// Returns every section between the first and last covered ones that
+    /// nobody covers.
+    pub(crate) fn uncovered(&self) -> IntervalSet<u32> {
+        self.covered_by(|elf_count| elf_count == 0)
+    }
+
+    /// Returns every section covered by more elves than the crowding
+    /// threshold.
+    pub(crate) fn crowded(&self) -> IntervalSet<u32> {
+        self.covered_by(|elf_count| elf_count > self.crowding_threshold)
+    }
+
+    /// Returns the most elves covering any one section, and every section
+    /// covered by that many.
+    pub(crate) fn max_coverage(&self) -> (usize, IntervalSet<u32>) {
+        let max_elf_count = self
+            .coverage_runs
+            .iter()
+            .map(|&(_, elf_count)| elf_count)
+            .max()
+            .unwrap_or(0);
+
+        (
+            max_elf_count,
+            self.covered_by(|elf_count| elf_count == max_elf_count),
+        )
+    }
+
+    /// Returns the position of every elf that can be dropped, together, without
+    /// changing which sections are covered.
+    pub(crate) fn redundant_elves(&self) -> Vec<usize> {
+        (0..self.elves.len())
+            .filter(|elf| self.essential_elves.binary_search(elf).is_err())
+            .collect()
+    }
+
+    /// Returns every section covered by a number of elves satisfying
+    /// `predicate`.
+    fn covered_by(&self, predicate: impl Fn(usize) -> bool) -> IntervalSet<u32> {
+        self.coverage_runs
+            .iter()
+            .filter(|&&(_, elf_count)| predicate(elf_count))
+            .map(|&(sections, _)| sections)
+            .collect()
+    }
+}
+
+impl Display for CampCoverage {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Elves: {}", self.elves.len())?;
+        if let (Some((first, _)), Some((last, _))) =
+            (self.coverage_runs.first(), self.coverage_runs.last())
+        {
+            write!(f, ", covering sections {}-{}", first.start(), last.end())?;
+        }
+        writeln!(f)?;
+
+        writeln!(f, "Uncovered sections: {}", describe(&self.uncovered()))?;
+        writeln!(
+            f,
+            "Sections covered by more than {} elves: {}",
+            self.crowding_threshold,
+            describe(&self.crowded())
+        )?;
+
+        let (max_elf_count, max_covered) = self.max_coverage();
+        writeln!(
+            f,
+            "Maximum coverage: {} elves, at sections {}",
+            max_elf_count,
+            describe(&max_covered)
+        )?;
+
+        let redundant_elves = self.redundant_elves();
+        writeln!(
+            f,
+            "Elves that can be dropped without changing coverage: {}",
+            redundant_elves.len()
+        )?;
+        for elf in redundant_elves {
+            let (line_number, section_assignment) = &self.elves[elf];
+
+            writeln!(
+                f,
+                "  elf {} (line {}): sections {}",
+                elf + 1,
+                line_number,
+                section_assignment.sections
+            )?;
+        }
+
+        std::fmt::Result::Ok(())
+    }
+}
+
+/// Returns `interval_set` as a comma-separated list of ranges, followed by
+/// how many sections they include.
+fn describe(interval_set: &IntervalSet<u32>) -> String {
+    if interval_set.is_empty() {
+        return "none".to_owned();
+    }
+
+    let ranges = interval_set
+        .intervals()
+        .iter()
+        .map(|interval| match interval.length() {
+            1 => interval.start().to_string(),
+            _ => interval.to_string(),
+        })
+        .collect::<Vec<String>>();
+
+    match interval_set.len() {
+        1 => format!("{} (1 section)", ranges.join(", ")),
+        len => format!("{} ({} sections)", ranges.join(", "), len),
+    }
+}
+
+/// Returns every run of sections covered by the same number of `elves`, from
+/// the first section anybody covers to the last, with that number.
+///
+/// Elves' assignments are swept in order of their boundaries, rather than
+/// section by section, so that the number of sections does not matter.
+fn sweep(elves: &[(usize, SectionAssignment)]) -> Vec<(Interval<u32>, usize)> {
+    // Every assignment adds an elf at its first section and removes it right
+    // after its last one, which could be just past the largest section id.
+    let mut boundaries = elves
+        .iter()
+        .flat_map(|(_, section_assignment)| {
+            let sections = section_assignment.sections;
+
+            [
+                (u64::from(*sections.start()), 1),
+                (u64::from(*sections.end()) + 1, -1),
+            ]
+        })
+        .collect::<Vec<(u64, i64)>>();
+    boundaries.sort_unstable();
+
+    let mut coverage_runs = vec![];
+    let mut elf_count = 0;
+    for (i, &(section, change)) in boundaries.iter().enumerate() {
+        elf_count += change;
+
+        let Some(&(next_section, _)) = boundaries.get(i + 1) else {
+            break;
+        };
+        if next_section > section {
+            let run = Interval::new(section as u32, (next_section - 1) as u32).unwrap();
+
+            coverage_runs.push((run, elf_count as usize));
+        }
+    }
+
+    coverage_runs
+}
+
+/// Returns the position of each elf in the smallest group of `elves` that
+/// covers the same sections as all of them, in order.
+///
+/// Elves are considered in order of their first section, and whenever the
+/// next uncovered section is reached, the elf that covers it and reaches
+/// furthest beyond it is picked.
+fn essential_elves(elves: &[(usize, SectionAssignment)]) -> Vec<usize> {
+    let start = |elf: usize| u64::from(*elves[elf].1.sections.start());
+    let end = |elf: usize| u64::from(*elves[elf].1.sections.end());
+
+    let mut elves_by_start = (0..elves.len()).collect::<Vec<usize>>();
+    elves_by_start.sort_by_key(|&elf| start(elf));
+
+    let mut essential_elves = vec![];
+    // Every section before this one is covered by the elves picked so far.
+    let mut covered_until = 0;
+    let mut i = 0;
+    while i < elves_by_start.len() {
+        // Skip ahead to the next elf's first section if there is a gap.
+        let next_section = covered_until.max(start(elves_by_start[i]));
+
+        let mut furthest_reaching = None;
+        while let Some(&elf) = elves_by_start.get(i) {
+            if start(elf) > next_section {
+                break;
+            }
+
+            if end(elf) >= next_section
+                && furthest_reaching.is_none_or(|other: usize| end(elf) > end(other))
+            {
+                furthest_reaching = Some(elf);
+            }
+
+            i += 1;
+        }
+
+        if let Some(elf) = furthest_reaching {
+            essential_elves.push(elf);
+            covered_until = end(elf) + 1;
+        }
+    }
+
+    essential_elves.sort_unstable();
+
+    essential_elves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Returns the [CampCoverage] of elves assigned to
+    /// `encoded_section_assignments`, one per line.
+    fn gather(encoded_section_assignments: &[&str], crowding_threshold: usize) -> CampCoverage {
+        let elves = encoded_section_assignments
+            .iter()
+            .enumerate()
+            .map(|(i, encoded_section_assignment)| {
+                (
+                    i + 1,
+                    SectionAssignment::parse(encoded_section_assignment).unwrap(),
+                )
+            })
+            .collect();
+
+        CampCoverage::gather(elves, crowding_threshold)
+    }
+
+    /// Returns the sections in `interval_set` as `(start, end)` pairs.
+    fn ranges(interval_set: &IntervalSet<u32>) -> Vec<(u32, u32)> {
+        interval_set
+            .intervals()
+            .iter()
+            .map(|interval| (*interval.start(), *interval.end()))
+            .collect()
+    }
+
+    #[test]
+    fn sweep_finds_gaps_and_crowds() {
+        let coverage = gather(&["2-4", "7-8", "2-3", "3-5", "10-10"], 2);
+
+        assert_eq!(ranges(&coverage.uncovered()), [(6, 6), (9, 9)]);
+        assert_eq!(ranges(&coverage.crowded()), [(3, 3)]);
+        assert_eq!(coverage.max_coverage().0, 3);
+        assert_eq!(ranges(&coverage.max_coverage().1), [(3, 3)]);
+    }
+
+    #[test]
+    fn redundant_elves_leave_coverage_unchanged() {
+        let coverage = gather(&["2-4", "6-8", "2-3", "3-7", "10-10", "5-8"], 1);
+
+        // "2-4" and "5-8" are enough to cover 2 through 8.
+        assert_eq!(coverage.redundant_elves(), [1, 2, 3]);
+        assert_eq!(coverage.essential_elves, [0, 4, 5]);
+    }
+
+    #[test]
+    fn the_largest_section_ids_are_covered() {
+        let coverage = gather(&["4294967290-4294967295", "1-1"], 0);
+
+        assert_eq!(ranges(&coverage.uncovered()), [(2, 4294967289)]);
+        assert!(coverage.redundant_elves().is_empty());
+    }
+}
@@ -1,58 +1,170 @@
 extern crate advent;
 extern crate anyhow;
+extern crate clap;
 extern crate lazy_static;
 extern crate regex;
 extern crate tokio;
 
+mod camp_coverage;
+mod overlap_index;
 mod section_assignment;
+mod section_assignment_group;
 mod section_assignment_pair;
 
-use anyhow::{anyhow, Context, Result};
-use section_assignment_pair::SectionAssignmentPair;
+use advent::{Config, Solution};
+use anyhow::{anyhow, Result};
+use camp_coverage::CampCoverage;
+use clap::Parser;
+use overlap_index::OverlapIndex;
+use section_assignment::SectionAssignment;
+use section_assignment_group::{parse_section_assignment_groups, SectionAssignmentGroup};
+
+/// Command line arguments for the "Camp Cleanup" puzzle.
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(flatten)]
+    config: Config,
+
+    /// Prints which sections are covered by how many elves, and which elves
+    /// could be dropped, instead of printing the answer.
+    #[arg(long)]
+    coverage: bool,
+
+    /// Specifies how many elves can cover a section before the coverage
+    /// report calls it crowded.
+    #[arg(default_value_t = 1, long)]
+    crowding: usize,
+
+    /// Prints every section assignment overlapping each of these section
+    /// ranges, like `10-20`, instead of printing the answer.
+    #[arg(long, value_name = "RANGE")]
+    overlapping: Vec<SectionAssignment>,
+
+    /// Accepts any number of section assignments on each line, instead of
+    /// exactly two.
+    #[arg(long)]
+    groups: bool,
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let config = advent::begin();
-
-    let encoded_section_assignment_pairs = advent::data(&config).await?;
-
-    let section_assignment_pairs = encoded_section_assignment_pairs
-        .lines()
-        .map(|encoded_section_assignment_pair| {
-            SectionAssignmentPair::parse(encoded_section_assignment_pair)
-        })
-        .collect::<Result<Vec<SectionAssignmentPair>>>()
-        .context("Failed to parse encoded section assignment pairs")?;
-
-    match config.part {
-        1 => {
-            let section_assignment_pairs_with_redundancy = section_assignment_pairs
-                .iter()
-                .filter(|section_assignment_pair| section_assignment_pair.has_rendundancy())
-                .collect::<Vec<&SectionAssignmentPair>>();
-
-            println!(
-                "Section assignment pairs with redundancy: {}\n{:?}",
-                section_assignment_pairs_with_redundancy.len(),
-                section_assignment_pairs_with_redundancy
+    let args = advent::begin_with_args::<Args>();
+    let config = args.config;
+
+    let encoded_section_assignment_groups = advent::data(&config).await?;
+
+    let camp_cleanup = CampCleanup {
+        any_group_size: args.groups,
+    };
+
+    if args.coverage || !args.overlapping.is_empty() {
+        let elves = camp_cleanup
+            .parse_groups(&encoded_section_assignment_groups)?
+            .iter()
+            .enumerate()
+            .flat_map(|(i, section_assignment_group)| {
+                section_assignment_group
+                    .section_assignments()
+                    .iter()
+                    .map(move |&section_assignment| (i + 1, section_assignment))
+            })
+            .collect::<Vec<(usize, SectionAssignment)>>();
+
+        if !args.overlapping.is_empty() {
+            let overlap_index = OverlapIndex::new(
+                &elves
+                    .iter()
+                    .map(|&(_, section_assignment)| section_assignment)
+                    .collect::<Vec<SectionAssignment>>(),
             );
 
-            Ok(())
+            for query in &args.overlapping {
+                let positions = overlap_index.overlapping(query);
+
+                println!(
+                    "Section assignments overlapping sections {}: {}",
+                    query.sections,
+                    positions.len()
+                );
+                for elf in positions {
+                    let (line_number, section_assignment) = &elves[elf];
+
+                    println!(
+                        "  elf {} (line {}): sections {}",
+                        elf + 1,
+                        line_number,
+                        section_assignment.sections
+                    );
+                }
+            }
         }
-        2 => {
-            let overlapping_section_assignment_pairs = section_assignment_pairs
-                .iter()
-                .filter(|section_assignment_pair| section_assignment_pair.has_overlap())
-                .collect::<Vec<&SectionAssignmentPair>>();
-
-            println!(
-                "Overlapping section assignment pairs: {}\n{:?}",
-                overlapping_section_assignment_pairs.len(),
-                overlapping_section_assignment_pairs,
-            );
 
-            Ok(())
+        if args.coverage {
+            print!("{}", CampCoverage::gather(elves, args.crowding));
         }
-        _ => Err(anyhow!("Part {} is not implemented", config.part)),
+
+        return Ok(());
     }
+
+    let answer = camp_cleanup.solve(config.part, &encoded_section_assignment_groups)?;
+
+    match (config.part, args.groups) {
+        (1, false) => println!("Section assignment pairs with redundancy: {}", answer),
+        (1, true) => println!("Section assignment groups with redundancy: {}", answer),
+        (_, false) => println!("Overlapping section assignment pairs: {}", answer),
+        (_, true) => println!("Section assignment groups with overlap: {}", answer),
+    }
+
+    Ok(())
+}
+
+/// Solution to the "Camp Cleanup" puzzle.
+#[derive(Default)]
+struct CampCleanup {
+    /// Whether each line can have any number of section assignments, instead
+    /// of exactly two.
+    any_group_size: bool,
 }
+
+impl CampCleanup {
+    /// Returns every [SectionAssignmentGroup] in
+    /// `encoded_section_assignment_groups`, one per line.
+    fn parse_groups(
+        &self,
+        encoded_section_assignment_groups: &str,
+    ) -> Result<Vec<SectionAssignmentGroup>> {
+        parse_section_assignment_groups(encoded_section_assignment_groups, self.any_group_size)
+    }
+}
+
+impl Solution for CampCleanup {
+    fn solve(&self, part: u8, encoded_section_assignment_groups: &str) -> Result<String> {
+        let section_assignment_groups = self.parse_groups(encoded_section_assignment_groups)?;
+
+        match part {
+            1 => {
+                let section_assignment_groups_with_redundancy = section_assignment_groups
+                    .iter()
+                    .filter(|section_assignment_group| section_assignment_group.has_redundancy())
+                    .count();
+
+                Ok(section_assignment_groups_with_redundancy.to_string())
+            }
+            2 => {
+                let overlapping_section_assignment_groups = section_assignment_groups
+                    .iter()
+                    .filter(|section_assignment_group| section_assignment_group.has_overlap())
+                    .count();
+
+                Ok(overlapping_section_assignment_groups.to_string())
+            }
+            _ => Err(anyhow!("Part {} is not implemented", part)),
+        }
+    }
+}
+
+advent::sample_tests!(CampCleanup::default());
+advent::reference_tests!(
+    CampCleanup::default(),
+    advent::generators::section_assignment_pairs()
+);
@@ -0,0 +1,152 @@
+use crate::section_assignment::SectionAssignment;
+
+/// Index of many [SectionAssignment]s that quickly finds every one
+/// overlapping a given range of sections.
+///
+/// Assignments are sorted by their first section and treated as a balanced
+/// binary tree, where each subtree spans a run of them and is rooted at the
+/// middle one. Knowing the furthest last section in each subtree lets a
+/// search skip any subtree that ends before the range, and everything to the
+/// right of an assignment that starts after it.
+#[derive(Debug)]
+pub(crate) struct OverlapIndex {
+    /// Position of each assignment among those indexed, with the assignment,
+    /// ordered by first section.
+    entries: Vec<(usize, SectionAssignment)>,
+    /// Furthest last section among the entries of the subtree rooted at each
+    /// entry.
+    subtree_ends: Vec<u32>,
+}
+
+impl OverlapIndex {
+    /// Returns the [OverlapIndex] of every one of `section_assignments`.
+    pub(crate) fn new(section_assignments: &[SectionAssignment]) -> OverlapIndex {
+        let mut entries = section_assignments
+            .iter()
+            .copied()
+            .enumerate()
+            .collect::<Vec<(usize, SectionAssignment)>>();
+        entries.sort_by_key(|(_, section_assignment)| *section_assignment.sections.start());
+
+        let mut overlap_index = OverlapIndex {
+            subtree_ends: vec![0; entries.len()],
+            entries,
+        };
+        overlap_index.index(0, overlap_index.entries.len());
+
+        overlap_index
+    }
+
+    /// Returns the position of every indexed assignment that overlaps
+    /// `section_assignment` at all, in order.
+    pub(crate) fn overlapping(&self, section_assignment: &SectionAssignment) -> Vec<usize> {
+        let mut positions = vec![];
+        self.search(0, self.entries.len(), section_assignment, &mut positions);
+        positions.sort_unstable();
+
+        positions
+    }
+
+    /// Fills in the subtree ends of the subtree spanning the entries from
+    /// `start` up to, but excluding, `end`, and returns the furthest of them.
+    fn index(&mut self, start: usize, end: usize) -> Option<u32> {
+        if start >= end {
+            return None;
+        }
+
+        let middle = start + (end - start) / 2;
+        let subtree_end = [
+            self.index(start, middle),
+            self.index(middle + 1, end),
+            Some(*self.entries[middle].1.sections.end()),
+        ]
+        .into_iter()
+        .flatten()
+        .max();
+
+        self.subtree_ends[middle] = subtree_end.unwrap();
+
+        subtree_end
+    }
+
+    /// Adds the position of every assignment that overlaps
+    /// `section_assignment` in the subtree spanning the entries from `start` up
+    /// to, but excluding, `end`, to `positions`.
+    fn search(
+        &self,
+        start: usize,
+        end: usize,
+        section_assignment: &SectionAssignment,
+        positions: &mut Vec<usize>,
+    ) {
+        if start >= end {
+            return;
+        }
+
+        let middle = start + (end - start) / 2;
+        if self.subtree_ends[middle] < *section_assignment.sections.start() {
+            return;
+        }
+
+        self.search(start, middle, section_assignment, positions);
+
+        let (position, other) = &self.entries[middle];
+        if other.sections.start() > section_assignment.sections.end() {
+            return;
+        }
+        if other.overlaps(section_assignment) {
+            positions.push(*position);
+        }
+
+        self.search(middle + 1, end, section_assignment, positions);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent::generators::section_assignments;
+    use proptest::prelude::*;
+
+    /// Returns the [SectionAssignment] equivalent to a generated one.
+    fn to_section_assignment((from, to): (u32, u32)) -> SectionAssignment {
+        SectionAssignment::parse(&format!("{}-{}", from, to)).unwrap()
+    }
+
+    #[test]
+    fn overlapping_finds_assignments_in_order() {
+        let section_assignments = ["5-9", "1-2", "3-4", "8-12", "2-6", "20-30"]
+            .map(|encoded| SectionAssignment::parse(encoded).unwrap());
+        let overlap_index = OverlapIndex::new(&section_assignments);
+
+        let query =
+            |encoded| overlap_index.overlapping(&SectionAssignment::parse(encoded).unwrap());
+        assert_eq!(query("4-8"), [0, 2, 3, 4]);
+        assert_eq!(query("13-19"), []);
+        assert_eq!(query("30-40"), [5]);
+        assert!(OverlapIndex::new(&[])
+            .overlapping(&section_assignments[0])
+            .is_empty());
+    }
+
+    proptest! {
+        #[test]
+        fn overlapping_agrees_with_linear_scan(
+            indexed in prop::collection::vec(section_assignments(), 0..50),
+            query in section_assignments(),
+        ) {
+            let indexed = indexed
+                .into_iter()
+                .map(to_section_assignment)
+                .collect::<Vec<SectionAssignment>>();
+            let query = to_section_assignment(query);
+
+            prop_assert_eq!(
+                OverlapIndex::new(&indexed).overlapping(&query),
+                (0..indexed.len())
+                    .filter(|&i| indexed[i].overlaps(&query))
+                    .collect::<Vec<usize>>()
+            );
+        }
+    }
+}
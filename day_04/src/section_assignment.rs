@@ -1,14 +1,15 @@
-use anyhow::{Context, Result};
+use std::str::FromStr;
+
+use advent::interval::Interval;
+use anyhow::{Context, Error, Result};
 use lazy_static::lazy_static;
 use regex::Regex;
 
 /// An inclusive range of section ids that an elf is responsible for.
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 pub(crate) struct SectionAssignment {
-    /// First section id included in this range.
-    pub(crate) from: u8,
-    /// Last section id included in this range.
-    pub(crate) to: u8,
+    /// Section ids included in this range.
+    pub(crate) sections: Interval<u32>,
 }
 
 impl SectionAssignment {
@@ -34,22 +35,82 @@ impl SectionAssignment {
         let first_chunk = parsed_encoded_section_assignment.get(1).unwrap().as_str();
         let second_chunk = parsed_encoded_section_assignment.get(2).unwrap().as_str();
 
-        let from = first_chunk.parse::<u8>()?;
-        let to = second_chunk.parse::<u8>()?;
+        let from = first_chunk.parse::<u32>()?;
+        let to = second_chunk.parse::<u32>()?;
+
+        let sections = Interval::new(from, to).with_context(|| {
+            format!(
+                "\"{}\" is not a valid encoded section assignment: {} comes after {}",
+                encoded_section_assignment, from, to
+            )
+        })?;
 
-        Ok(SectionAssignment { from: from, to: to })
+        Ok(SectionAssignment { sections })
     }
 
     /// Returns `true` if this [SectionAssignment] fully contains the `other`
     /// one.
     pub(crate) fn fully_contains(&self, other: &Self) -> bool {
-        self.from <= other.from && self.to >= other.to
+        self.sections.fully_contains(&other.sections)
     }
 
     /// Returns `true` if this [SectionAssignment] at all overlaps the `other`
     /// one.
     pub(crate) fn overlaps(&self, other: &Self) -> bool {
-        (self.from <= other.from && self.to >= other.from)
-            || (other.from <= self.from && other.to >= self.from)
+        self.sections.overlaps(&other.sections)
+    }
+}
+
+impl FromStr for SectionAssignment {
+    type Err = Error;
+
+    /// Returns the [SectionAssignment] that `s`, like `2-4`, is encoded as.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        SectionAssignment::parse(s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent::generators::{section_assignments, SectionAssignmentPairsInput};
+    use proptest::prelude::*;
+
+    /// Returns the [SectionAssignment] equivalent to a generated one.
+    fn to_section_assignment((from, to): (u32, u32)) -> SectionAssignment {
+        SectionAssignment::parse(&format!("{}-{}", from, to)).unwrap()
+    }
+
+    #[test]
+    fn parse_handles_large_and_backwards_section_ids() {
+        assert_eq!(
+            SectionAssignment::parse("250-1000").unwrap().sections,
+            Interval::new(250, 1000).unwrap()
+        );
+        assert!(SectionAssignment::parse("7-3").is_err());
+    }
+
+    proptest! {
+        #[test]
+        fn fully_contains_agrees_with_naive_implementation(
+            a in section_assignments(),
+            b in section_assignments(),
+        ) {
+            prop_assert_eq!(
+                to_section_assignment(a).fully_contains(&to_section_assignment(b)),
+                SectionAssignmentPairsInput::fully_contains_naively(a, b)
+            );
+        }
+
+        #[test]
+        fn overlaps_agrees_with_naive_implementation(
+            a in section_assignments(),
+            b in section_assignments(),
+        ) {
+            prop_assert_eq!(
+                to_section_assignment(a).overlaps(&to_section_assignment(b)),
+                SectionAssignmentPairsInput::overlaps_naively(a, b)
+            );
+        }
     }
 }
@@ -0,0 +1,98 @@
+use anyhow::{Context, Result};
+
+use crate::section_assignment::SectionAssignment;
+use crate::section_assignment_pair::SectionAssignmentPair;
+
+/// Team of any number of elves, each with their own [SectionAssignment].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SectionAssignmentGroup(Vec<SectionAssignment>);
+
+impl SectionAssignmentGroup {
+    /// Returns the constructed [SectionAssignmentGroup] that best corresponds
+    /// with the specified `encoded_section_assignment_group`, whose
+    /// assignments are separated by commas.
+    pub(crate) fn parse(encoded_section_assignment_group: &str) -> Result<SectionAssignmentGroup> {
+        encoded_section_assignment_group
+            .split(',')
+            .map(SectionAssignment::parse)
+            .collect::<Result<Vec<SectionAssignment>>>()
+            .map(SectionAssignmentGroup)
+    }
+
+    /// Returns every one of this group's assignments, in order.
+    pub(crate) fn section_assignments(&self) -> &[SectionAssignment] {
+        &self.0
+    }
+
+    /// Returns the positions of every two of this group's assignments that
+    /// overlap at all, in order.
+    pub(crate) fn overlapping_pairs(&self) -> Vec<(usize, usize)> {
+        (0..self.0.len())
+            .flat_map(|i| (i + 1..self.0.len()).map(move |j| (i, j)))
+            .filter(|&(i, j)| self.0[i].overlaps(&self.0[j]))
+            .collect()
+    }
+
+    /// Returns `true` if any two of this group's assignments overlap at all.
+    pub(crate) fn has_overlap(&self) -> bool {
+        !self.overlapping_pairs().is_empty()
+    }
+
+    /// Returns `true` if one of this group's assignments is fully contained by
+    /// another.
+    pub(crate) fn has_redundancy(&self) -> bool {
+        self.0.iter().enumerate().any(|(i, section_assignment)| {
+            self.0
+                .iter()
+                .enumerate()
+                .any(|(j, other)| i != j && other.fully_contains(section_assignment))
+        })
+    }
+}
+
+/// Returns every [SectionAssignmentGroup] in
+/// `encoded_section_assignment_groups`, one per line.
+///
+/// * `any_group_size` is whether each line can have any number of section
+///   assignments, instead of exactly two
+pub(crate) fn parse_section_assignment_groups(
+    encoded_section_assignment_groups: &str,
+    any_group_size: bool,
+) -> Result<Vec<SectionAssignmentGroup>> {
+    encoded_section_assignment_groups
+        .lines()
+        .map(|line| {
+            if any_group_size {
+                SectionAssignmentGroup::parse(line)
+            } else {
+                SectionAssignmentPair::parse(line).map(SectionAssignmentGroup::from)
+            }
+        })
+        .collect::<Result<Vec<SectionAssignmentGroup>>>()
+        .context("Failed to parse encoded section assignment groups")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn groups_can_have_any_number_of_assignments() {
+        let group = SectionAssignmentGroup::parse("2-4,6-8,3-6,10-12").unwrap();
+
+        assert_eq!(group.section_assignments().len(), 4);
+        assert_eq!(group.overlapping_pairs(), [(0, 2), (1, 2)]);
+        assert!(!group.has_redundancy());
+
+        let group = SectionAssignmentGroup::parse("2-4,6-8,7-7").unwrap();
+        assert!(group.has_redundancy());
+        assert!(!SectionAssignmentGroup::parse("2-4").unwrap().has_overlap());
+    }
+
+    #[test]
+    fn identical_assignments_are_redundant() {
+        assert!(SectionAssignmentGroup::parse("1-3,5-6,1-3")
+            .unwrap()
+            .has_redundancy());
+    }
+}
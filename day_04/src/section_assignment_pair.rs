@@ -1,44 +1,60 @@
+use std::ops::Deref;
+
 use anyhow::{anyhow, Result};
 
-use crate::section_assignment::SectionAssignment;
+use crate::section_assignment_group::SectionAssignmentGroup;
 
-/// A tuple of two [SectionAssignment] instances.
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
-pub(crate) struct SectionAssignmentPair(SectionAssignment, SectionAssignment);
+/// A [SectionAssignmentGroup] of exactly two elves.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub(crate) struct SectionAssignmentPair(SectionAssignmentGroup);
 
 impl SectionAssignmentPair {
     /// Returns the constructed [SectionAssignmentPair] that best corresponds
     /// with the specified `encoded_section_assignment_pair`.
     pub(crate) fn parse(encoded_section_assignment_pair: &str) -> Result<SectionAssignmentPair> {
-        let parts = encoded_section_assignment_pair
-            .split(',')
-            .collect::<Vec<&str>>();
+        let part_count = encoded_section_assignment_pair.split(',').count();
 
-        if parts.len() != 2 {
+        if part_count != 2 {
             return Err(anyhow!(
                 "\"{}\" is not a valid encoded section assignment pair: it has {} parts",
                 encoded_section_assignment_pair,
-                parts.len()
+                part_count
             ));
         }
 
-        let first_section_assignment = SectionAssignment::parse(parts[0])?;
-        let second_section_assignment = SectionAssignment::parse(parts[1])?;
+        SectionAssignmentGroup::parse(encoded_section_assignment_pair).map(SectionAssignmentPair)
+    }
+}
+
+impl Deref for SectionAssignmentPair {
+    type Target = SectionAssignmentGroup;
 
-        Ok(SectionAssignmentPair(
-            first_section_assignment,
-            second_section_assignment,
-        ))
+    fn deref(&self) -> &Self::Target {
+        &self.0
     }
+}
 
-    /// Returns `true` if this pair's assignments overlap at all.
-    pub(crate) fn has_overlap(&self) -> bool {
-        self.0.overlaps(&self.1)
+impl From<SectionAssignmentPair> for SectionAssignmentGroup {
+    fn from(section_assignment_pair: SectionAssignmentPair) -> Self {
+        section_assignment_pair.0
     }
+}
 
-    /// Returns `true` if one of this pair's assignments is fully contained by
-    /// the other.
-    pub(crate) fn has_rendundancy(&self) -> bool {
-        self.0.fully_contains(&self.1) || self.1.fully_contains(&self.0)
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pairs_are_groups_of_exactly_two() {
+        let section_assignment_pair = SectionAssignmentPair::parse("2-8,3-7").unwrap();
+        assert!(section_assignment_pair.has_redundancy());
+        assert!(section_assignment_pair.has_overlap());
+
+        assert_eq!(
+            SectionAssignmentPair::parse("2-8,3-7,4-5")
+                .unwrap_err()
+                .to_string(),
+            "\"2-8,3-7,4-5\" is not a valid encoded section assignment pair: it has 3 parts"
+        );
     }
 }
@@ -0,0 +1,197 @@
+use anyhow::{anyhow, Context, Result};
+
+use crate::crate_piling_order::CratePilingOrder;
+use crate::crate_stacks::{Crate, CrateStack};
+
+/// Model of crane that carries out crate moves.
+pub(crate) trait Crane {
+    /// Takes `number_of_crates` crates off `origin_crate_stack`, and returns
+    /// them in the order they are piled onto the destination stack, or
+    /// returns [None], leaving the stack untouched, if it does not have that
+    /// many.
+    fn lift(
+        &self,
+        origin_crate_stack: &mut CrateStack,
+        number_of_crates: usize,
+    ) -> Option<Vec<Crate>>;
+
+    /// Puts `crates`, as returned by [Crane::lift], back on
+    /// `origin_crate_stack` exactly where they were lifted from.
+    fn put_back(&self, origin_crate_stack: &mut CrateStack, crates: Vec<Crate>);
+}
+
+/// Lifts every crate of a move off the top of the stack at once, then piles
+/// them on in the specified order.
+///
+/// Flipping crates is the same as lifting them one at a time, like the
+/// CrateMover 9000, and keeping them in order is the CrateMover 9001.
+impl Crane for CratePilingOrder {
+    fn lift(
+        &self,
+        origin_crate_stack: &mut CrateStack,
+        number_of_crates: usize,
+    ) -> Option<Vec<Crate>> {
+        let mut crates = origin_crate_stack.pop(number_of_crates)?;
+
+        if let CratePilingOrder::Flipped = self {
+            crates.reverse();
+        }
+
+        Some(crates)
+    }
+
+    fn put_back(&self, origin_crate_stack: &mut CrateStack, mut crates: Vec<Crate>) {
+        if let CratePilingOrder::Flipped = self {
+            crates.reverse();
+        }
+
+        origin_crate_stack.crates.extend(crates);
+    }
+}
+
+/// CrateMover 9001 that can only lift so many crates at a time, and splits
+/// bigger moves into several lifts off the top of the stack.
+#[derive(Debug)]
+pub(crate) struct CapacityLimitedCrane {
+    /// Most crates lifted at once.
+    pub(crate) capacity: usize,
+}
+
+impl Crane for CapacityLimitedCrane {
+    fn lift(
+        &self,
+        origin_crate_stack: &mut CrateStack,
+        number_of_crates: usize,
+    ) -> Option<Vec<Crate>> {
+        if origin_crate_stack.crates.len() < number_of_crates {
+            return None;
+        }
+
+        let mut crates = vec![];
+        let mut remaining_crate_count = number_of_crates;
+        while remaining_crate_count > 0 {
+            let lifted_crate_count = remaining_crate_count.min(self.capacity);
+
+            crates.extend(CratePilingOrder::InOrder.lift(origin_crate_stack, lifted_crate_count)?);
+            remaining_crate_count -= lifted_crate_count;
+        }
+
+        Some(crates)
+    }
+
+    fn put_back(&self, origin_crate_stack: &mut CrateStack, crates: Vec<Crate>) {
+        // The first lift took the topmost crates, so it goes back last.
+        for lifted_crates in crates.chunks(self.capacity).rev() {
+            origin_crate_stack.crates.extend_from_slice(lifted_crates);
+        }
+    }
+}
+
+/// Crane that slides crates out from the bottom of the stack, and piles them
+/// on in the same order.
+#[derive(Debug)]
+pub(crate) struct BottomCrane;
+
+impl Crane for BottomCrane {
+    fn lift(
+        &self,
+        origin_crate_stack: &mut CrateStack,
+        number_of_crates: usize,
+    ) -> Option<Vec<Crate>> {
+        if origin_crate_stack.crates.len() < number_of_crates {
+            return None;
+        }
+
+        Some(
+            origin_crate_stack
+                .crates
+                .drain(..number_of_crates)
+                .collect(),
+        )
+    }
+
+    fn put_back(&self, origin_crate_stack: &mut CrateStack, crates: Vec<Crate>) {
+        origin_crate_stack.crates.splice(..0, crates);
+    }
+}
+
+/// Returns the [Crane] with the specified `name`: `9000`, `9001`, `9001:N` for
+/// a CrateMover 9001 that lifts at most `N` crates at a time, or `bottom`.
+pub(crate) fn crane_named(name: &str) -> Result<Box<dyn Crane>> {
+    match name {
+        "9000" => Ok(Box::new(CratePilingOrder::Flipped)),
+        "9001" => Ok(Box::new(CratePilingOrder::InOrder)),
+        "bottom" => Ok(Box::new(BottomCrane)),
+        _ => {
+            let capacity = name
+                .strip_prefix("9001:")
+                .with_context(|| {
+                    format!(
+                        "\"{}\" is not a known crane: try 9000, 9001, 9001:N, or bottom",
+                        name
+                    )
+                })?
+                .parse::<usize>()
+                .with_context(|| format!("\"{}\" does not have a valid capacity", name))?;
+
+            if capacity == 0 {
+                return Err(anyhow!("\"{}\" cannot lift any crates", name));
+            }
+
+            Ok(Box::new(CapacityLimitedCrane { capacity }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crate_stacks::CrateStacks;
+
+    /// Returns the crate stacks that moving every crate from stack 1 to stack
+    /// 2 leaves behind, using the crane with the specified `name`.
+    fn move_everything(name: &str) -> String {
+        let mut crate_stacks =
+            CrateStacks::parse("[E]    \n[D]    \n[C]    \n[B]    \n[A] [Z]\n 1   2 ").unwrap();
+
+        crate_stacks
+            .apply_move(
+                &crate::crate_move::CrateMove::parse("move 5 from 1 to 2").unwrap(),
+                crane_named(name).unwrap().as_ref(),
+            )
+            .unwrap();
+
+        crate_stacks.to_string()
+    }
+
+    #[test]
+    fn capacity_limited_cranes_split_big_moves() {
+        assert_eq!(
+            move_everything("9001:2"),
+            "    [A]\n    [C]\n    [B]\n    [E]\n    [D]\n    [Z]\n 1   2 "
+        );
+        assert_eq!(move_everything("9001:1"), move_everything("9000"));
+        assert_eq!(move_everything("9001:5"), move_everything("9001"));
+    }
+
+    #[test]
+    fn bottom_cranes_move_the_bottom_crates() {
+        let mut crate_stacks = CrateStacks::parse("[B]    \n[A] [Z]\n 1   2 ").unwrap();
+
+        crate_stacks
+            .apply_move(
+                &crate::crate_move::CrateMove::parse("move 1 from 1 to 2").unwrap(),
+                &BottomCrane,
+            )
+            .unwrap();
+
+        assert_eq!(crate_stacks.to_string(), "    [A]\n[B] [Z]\n 1   2 ");
+    }
+
+    #[test]
+    fn crane_named_rejects_unknown_cranes() {
+        assert!(crane_named("9002").is_err());
+        assert!(crane_named("9001:0").is_err());
+        assert!(crane_named("9001:x").is_err());
+    }
+}
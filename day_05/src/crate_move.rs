@@ -1,16 +1,18 @@
-use anyhow::{anyhow, Context, Result};
+use std::fmt::Display;
+
+use anyhow::{Context, Result};
 use lazy_static::lazy_static;
 use regex::{Regex, RegexBuilder};
 
 /// Represents the relocation of some number of crates between crate stacks.
-#[derive(Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, PartialEq)]
 pub(crate) struct CrateMove {
-    /// Index of the stack that should receive `number_of_crates` crates.
-    pub(crate) destination_crate_index: usize,
+    /// Label of the stack that should receive `number_of_crates` crates.
+    pub(crate) destination_label: String,
     /// How many crates will move.
     pub(crate) number_of_crates: usize,
-    /// Index of the stack that should lose `number_of_crates` crates.
-    pub(crate) origin_crate_index: usize,
+    /// Label of the stack that should lose `number_of_crates` crates.
+    pub(crate) origin_label: String,
 }
 
 impl CrateMove {
@@ -19,9 +21,9 @@ impl CrateMove {
     pub(crate) fn parse(encoded_crate_move: &str) -> Result<CrateMove> {
         lazy_static! {
           /// Regular expression designed to match strings that look like
-          /// "move\t1   from 2 to 1 ".
+          /// "move\t1   from 2 to 1 " or "move 3 from A to 10".
           static ref ENCODED_CRATE_MOVE_PATTERN: Regex =
-          RegexBuilder::new(r"^\s*move\s+(\d+)\s+from\s+(\d+)\s+to\s+(\d+)\s*$").case_insensitive(true).build().unwrap();
+          RegexBuilder::new(r"^\s*move\s+(\d+)\s+from\s+(\S+)\s+to\s+(\S+)\s*$").case_insensitive(true).build().unwrap();
         }
 
         let parsed_encoded_crate_move = ENCODED_CRATE_MOVE_PATTERN
@@ -61,37 +63,28 @@ impl CrateMove {
             })?
             .as_str();
 
-        let destination_crate_number = third_chunk
-            .parse::<usize>()
-            .with_context(|| format!("Failed to parse \"{}\" as an integer", third_chunk))?;
         let number_of_crates = first_chunk
             .parse::<usize>()
             .with_context(|| format!("Failed to parse \"{}\" as an integer", first_chunk))?;
-        let origin_crate_number = second_chunk
-            .parse::<usize>()
-            .with_context(|| format!("Failed to parse \"{}\" as an integer", second_chunk))?;
-
-        if destination_crate_number < 1 {
-            return Err(anyhow!(
-                "detination crate number, {}, is out of range",
-                destination_crate_number
-            ));
-        }
-        if origin_crate_number < 1 {
-            return Err(anyhow!(
-                "origin crate number, {}, is out of range",
-                origin_crate_number
-            ));
-        }
 
         Ok(CrateMove {
-            destination_crate_index: destination_crate_number - 1,
-            number_of_crates: number_of_crates,
-            origin_crate_index: origin_crate_number - 1,
+            destination_label: third_chunk.to_owned(),
+            number_of_crates,
+            origin_label: second_chunk.to_owned(),
         })
     }
 }
 
+impl Display for CrateMove {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "move {} from {} to {}",
+            self.number_of_crates, self.origin_label, self.destination_label
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -101,9 +94,9 @@ mod tests {
         assert_eq!(
             CrateMove::parse("move 1 from 2 to 1").unwrap(),
             CrateMove {
-                destination_crate_index: 0,
+                destination_label: "1".to_owned(),
                 number_of_crates: 1,
-                origin_crate_index: 1,
+                origin_label: "2".to_owned(),
             },
         );
     }
@@ -113,9 +106,9 @@ mod tests {
         assert_eq!(
             CrateMove::parse("MOVE 12 FROM 8 TO 9").unwrap(),
             CrateMove {
-                destination_crate_index: 8,
+                destination_label: "9".to_owned(),
                 number_of_crates: 12,
-                origin_crate_index: 7,
+                origin_label: "8".to_owned(),
             },
         );
     }
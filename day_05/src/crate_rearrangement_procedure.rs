@@ -1,9 +1,12 @@
-use anyhow::{anyhow, Context, Result};
+use std::fmt::Display;
+
+use anyhow::{anyhow, Context, Error, Result};
 use lazy_static::lazy_static;
 use regex::Regex;
 
 use crate::{
-    crate_move::CrateMove, crate_piling_order::CratePilingOrder, crate_stacks::CrateStacks,
+    crane::Crane, crate_move::CrateMove, crate_stacks::CrateStacks,
+    rearrangement_history::RearrangementHistory,
 };
 
 /// Represents the relocation of some number of crates between crate stacks.
@@ -41,24 +44,60 @@ impl CrateRearrangementProcedure {
 
         let crate_moves = encoded_rearrangement_procedure_halves[1]
             .lines()
-            .map(|encoded_crate_move| CrateMove::parse(encoded_crate_move))
+            .map(CrateMove::parse)
             .collect::<Result<Vec<CrateMove>>>()
             .context("Failed to read first half of rearrangement procedure")?;
         let crate_stacks = CrateStacks::parse(encoded_rearrangement_procedure_halves[0])
             .context("Failed to read first half of rearrangement procedure")?;
 
         Ok(CrateRearrangementProcedure {
-            crate_moves: crate_moves,
-            crate_stacks: crate_stacks,
+            crate_moves,
+            crate_stacks,
         })
     }
 
     /// Returns the result of applying this rearrangement procedure's crate
-    /// moves to a clone of its crate stacks.
+    /// moves to a clone of its crate stacks, one move at a time.
     ///
-    ///  * `create_piling_order` describes the piling order that should be used
-    pub(crate) fn execute(&self, create_piling_order: CratePilingOrder) -> CrateStacks {
+    ///  * `crane` carries out each move
+    ///  * `on_invalid_move` is invoked with the error explaining each move that
+    ///    cannot be carried out, which is skipped unless it returns that error
+    ///  * `on_step` is invoked with each move carried out and the rearranged
+    ///    crate stacks after it
+    pub(crate) fn execute_stepwise(
+        &self,
+        crane: &dyn Crane,
+        on_invalid_move: impl FnMut(Error) -> Result<()>,
+        on_step: impl FnMut(&CrateMove, &CrateStacks),
+    ) -> Result<CrateStacks> {
         self.crate_stacks
-            .apply(&self.crate_moves, create_piling_order)
+            .apply_stepwise(&self.crate_moves, crane, on_invalid_move, on_step)
+    }
+
+    /// Returns the crate stacks that this rearrangement procedure's crate
+    /// moves started from, treating its crate stacks as the result.
+    ///
+    ///  * `crane` must be the one that carried out the moves
+    pub(crate) fn reverse(&self, crane: &dyn Crane) -> Result<CrateStacks> {
+        let mut history =
+            RearrangementHistory::after(self.crate_stacks.clone(), &self.crate_moves, crane);
+
+        while history.undo()?.is_some() {}
+
+        Ok(history.crate_stacks().clone())
+    }
+}
+
+impl Display for CrateRearrangementProcedure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}\n\n", self.crate_stacks)?;
+
+        let encoded_crate_moves = self
+            .crate_moves
+            .iter()
+            .map(|crate_move| crate_move.to_string())
+            .collect::<Vec<String>>();
+
+        write!(f, "{}", encoded_crate_moves.join("\n"))
     }
 }
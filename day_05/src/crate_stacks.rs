@@ -1,86 +1,199 @@
-use anyhow::{Context, Ok, Result};
+use std::fmt::Display;
+use std::ops::Range;
+
+use advent::renderer::{Canvas, Color, Picture};
+use advent::visualizer::Frame;
+use anyhow::{anyhow, Context, Error, Ok, Result};
 use lazy_static::lazy_static;
 use regex::Regex;
 
-use crate::{crate_move::CrateMove, crate_piling_order::CratePilingOrder};
+use crate::{crane::Crane, crate_move::CrateMove};
 
-#[derive(Clone, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub(crate) struct CrateStacks(Vec<CrateStack>);
 
 impl CrateStacks {
     /// Returns the constructed [CrateStacks] instance that best corresponds to
     /// the specified `encoded_crate_stacks`.
+    ///
+    /// Stacks are labeled by the last row, and each crate belongs to the stack
+    /// whose label it sits above, so labels and crates can be any width.
     pub(crate) fn parse(encoded_crate_stacks: &str) -> Result<CrateStacks> {
         lazy_static! {
-          /// Regular expression designed to match the numbers at the bottom
+          /// Regular expression designed to match the labels at the bottom
           /// of the encoded crate stacks.
           static ref LABEL_ROW_PATTERN: Regex =
-              Regex::new(r"\n(?:\s+(\d+))+\s*").unwrap();
+              Regex::new(r"(?:^|\n)((?:[^\S\n]*[^\s\[\]]+)+)\s*$").unwrap();
+
+          /// Regular expression designed to match each crate or label in a
+          /// row.
+          static ref TOKEN_PATTERN: Regex =
+              Regex::new(r"\S+").unwrap();
         }
 
-        let label_row_start_index = LABEL_ROW_PATTERN
-            .find(encoded_crate_stacks)
-            .context("Failed to find the crate labels row")?
-            .range()
-            .start;
+        let label_row_captures = LABEL_ROW_PATTERN
+            .captures(encoded_crate_stacks)
+            .context("Failed to find the crate labels row")?;
+        let label_row = label_row_captures.get(1).unwrap().as_str();
+
+        let crate_rows = &encoded_crate_stacks[..label_row_captures.get(0).unwrap().start()];
 
-        let crate_rows = &encoded_crate_stacks[..label_row_start_index];
+        let labels = columns(label_row, &TOKEN_PATTERN);
+        for (index, (label, _)) in labels.iter().enumerate() {
+            if labels[..index].iter().any(|(other, _)| other == label) {
+                return Err(anyhow!("Stack {} is labeled more than once", label));
+            }
+        }
 
         let crates = crate_rows
             .lines()
-            .map(|crate_row| {
-                crate_row
-                    .chars()
-                    .collect::<Vec<char>>()
-                    .chunks(4)
-                    .map(|crate_chunk| crate_chunk.iter().collect::<String>())
-                    .map(|encoded_crate| Crate::parse(&encoded_crate))
-                    .collect::<Result<Vec<Option<Crate>>>>()
-            })
-            .collect::<Result<Vec<Vec<Option<Crate>>>>>()
-            .context("Failed to chunkify crate rows")?;
+            .enumerate()
+            .map(|(row_index, crate_row)| {
+                let mut crate_row_by_stack = vec![None; labels.len()];
 
-        let row_count = crates.len();
-        let column_count = if row_count >= 1 { crates[0].len() } else { 0 };
+                for (encoded_crate, crate_span) in columns(crate_row, &TOKEN_PATTERN) {
+                    let stack_index =
+                        stack_index_below(&labels, crate_span).with_context(|| {
+                            format!(
+                                "Crate \"{}\" in row {} is not above any stack label",
+                                encoded_crate,
+                                row_index + 1
+                            )
+                        })?;
 
-        if crates.len() < 1 {
-            return Ok(CrateStacks(vec![]));
-        }
+                    if crate_row_by_stack[stack_index].is_some() {
+                        return Err(anyhow!(
+                            "Row {} has more than one crate above stack {}",
+                            row_index + 1,
+                            labels[stack_index].0
+                        ));
+                    }
+
+                    crate_row_by_stack[stack_index] = Some(Crate::parse(encoded_crate)?);
+                }
+
+                Ok(crate_row_by_stack)
+            })
+            .collect::<Result<Vec<Vec<Option<Crate>>>>>()
+            .context("Failed to read crate rows")?;
 
-        let crate_stacks = (0..column_count)
-            .map(|column_index| {
-                (0..row_count)
+        let crate_stacks = labels
+            .iter()
+            .enumerate()
+            .map(|(stack_index, (label, _))| {
+                let crate_column = crates
+                    .iter()
                     .rev()
-                    .map(|row_index| crates[row_index][column_index])
+                    .map(|crate_row| crate_row[stack_index].clone())
+                    .collect::<Vec<Option<Crate>>>();
+
+                let stack_height = crate_column
+                    .iter()
                     .take_while(|maybe_crate| maybe_crate.is_some())
-                    .map(|maybe_crate| maybe_crate.unwrap())
-                    .collect::<Vec<Crate>>()
+                    .count();
+
+                if crate_column[stack_height..]
+                    .iter()
+                    .any(|maybe_crate| maybe_crate.is_some())
+                {
+                    return Err(anyhow!(
+                        "Stack {} has a crate floating above an empty space",
+                        label
+                    ));
+                }
+
+                Ok(CrateStack {
+                    crates: crate_column.into_iter().flatten().collect(),
+                    label: label.to_string(),
+                })
             })
-            .map(|crate_column| CrateStack(crate_column))
-            .collect::<Vec<CrateStack>>();
+            .collect::<Result<Vec<CrateStack>>>()?;
 
         Ok(CrateStacks(crate_stacks))
     }
 
-    /// Applies the specified `crate_moves` to a clone of this [CrateStacks].
+    /// Applies the specified `crate_moves` to a clone of this [CrateStacks],
+    /// one move at a time.
     ///
-    ///  * `create_piling_order` describes the piling order that should be used
-    pub(crate) fn apply(
+    ///  * `crane` carries out each move
+    ///  * `on_invalid_move` is invoked with the error explaining each move that
+    ///    cannot be carried out, which is skipped unless it returns that error
+    ///  * `on_step` is invoked with each move carried out and the rearranged
+    ///    crate stacks after it
+    pub(crate) fn apply_stepwise(
         &self,
-        crate_moves: &Vec<CrateMove>,
-        create_piling_order: CratePilingOrder,
-    ) -> CrateStacks {
-        let mut crate_stacks = self.0.clone();
+        crate_moves: &[CrateMove],
+        crane: &dyn Crane,
+        mut on_invalid_move: impl FnMut(Error) -> Result<()>,
+        mut on_step: impl FnMut(&CrateMove, &CrateStacks),
+    ) -> Result<CrateStacks> {
+        let mut crate_stacks = self.clone();
 
-        for crate_move in crate_moves {
-            let moved_crates =
-                crate_stacks[crate_move.origin_crate_index].pop(crate_move.number_of_crates);
+        for (index, crate_move) in crate_moves.iter().enumerate() {
+            if let Err(error) = crate_stacks.apply_move(crate_move, crane) {
+                on_invalid_move(error.context(format!(
+                    "Move {} ({}) is invalid",
+                    index + 1,
+                    crate_move
+                )))?;
 
-            crate_stacks[crate_move.destination_crate_index]
-                .pile_on(moved_crates, &create_piling_order);
+                continue;
+            }
+
+            on_step(crate_move, &crate_stacks);
         }
 
-        CrateStacks(crate_stacks)
+        Ok(crate_stacks)
+    }
+
+    /// Applies the specified `crate_move` to this [CrateStacks], leaving it
+    /// untouched if the move cannot be carried out.
+    ///
+    ///  * `crane` carries out the move
+    pub(crate) fn apply_move(&mut self, crate_move: &CrateMove, crane: &dyn Crane) -> Result<()> {
+        let origin_index = self.stack_index(&crate_move.origin_label)?;
+        let destination_index = self.stack_index(&crate_move.destination_label)?;
+
+        let origin_crate_stack = &mut self.0[origin_index];
+        let moved_crates = crane
+            .lift(origin_crate_stack, crate_move.number_of_crates)
+            .with_context(|| origin_crate_stack.shortage())?;
+
+        self.0[destination_index].crates.extend(moved_crates);
+
+        Ok(())
+    }
+
+    /// Reverts the specified `crate_move` on this [CrateStacks], which must be
+    /// the last one applied to it, leaving it untouched if the move cannot
+    /// have been carried out.
+    ///
+    ///  * `crane` must be the one that carried out the move
+    pub(crate) fn undo_move(&mut self, crate_move: &CrateMove, crane: &dyn Crane) -> Result<()> {
+        let origin_index = self.stack_index(&crate_move.origin_label)?;
+        let destination_index = self.stack_index(&crate_move.destination_label)?;
+
+        let destination_crate_stack = &mut self.0[destination_index];
+        let moved_crates = destination_crate_stack
+            .pop(crate_move.number_of_crates)
+            .with_context(|| destination_crate_stack.shortage())?;
+
+        crane.put_back(&mut self.0[origin_index], moved_crates);
+
+        Ok(())
+    }
+
+    /// Returns every stack, from left to right.
+    pub(crate) fn crate_stacks(&self) -> &[CrateStack] {
+        &self.0
+    }
+
+    /// Returns the position of the stack labeled `label`.
+    fn stack_index(&self, label: &str) -> Result<usize> {
+        self.0
+            .iter()
+            .position(|crate_stack| crate_stack.label == label)
+            .with_context(|| format!("there is no stack {}", label))
     }
 
     /// Returns the [Crate] at the top of each stack.
@@ -89,71 +202,178 @@ impl CrateStacks {
     }
 }
 
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub(crate) struct CrateStack(Vec<Crate>);
+impl Display for CrateStacks {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let height = self
+            .0
+            .iter()
+            .map(|crate_stack| crate_stack.crates.len())
+            .max()
+            .unwrap_or(0);
 
-impl CrateStack {
-    /// Returns the [Crate] at the top of this stack.
-    ///
-    ///  * `create_piling_order` describes the piling order that should be used
-    pub(crate) fn pile_on(&mut self, crates: Vec<Crate>, create_piling_order: &CratePilingOrder) {
-        match create_piling_order {
-            CratePilingOrder::Flipped => {
-                for piled_on_crate in crates.iter().rev() {
-                    self.0.push(*piled_on_crate);
+        // Each stack's column is wide enough for its widest crate, and for its
+        // label with a space on either side, so every crate sits right above
+        // its label.
+        let column_widths = self
+            .0
+            .iter()
+            .map(|crate_stack| {
+                crate_stack
+                    .crates
+                    .iter()
+                    .map(|stacked_crate| stacked_crate.label.chars().count())
+                    .chain([crate_stack.label.chars().count()])
+                    .max()
+                    .unwrap()
+                    + 2
+            })
+            .collect::<Vec<usize>>();
+
+        for level in (0..height).rev() {
+            let crate_row = self
+                .0
+                .iter()
+                .zip(&column_widths)
+                .map(
+                    |(crate_stack, &width)| match crate_stack.crates.get(level) {
+                        Some(stacked_crate) => {
+                            centered(&format!("[{}]", stacked_crate.label), width)
+                        }
+                        None => " ".repeat(width),
+                    },
+                )
+                .collect::<Vec<String>>();
+
+            writeln!(f, "{}", crate_row.join(" "))?;
+        }
+
+        let label_row = self
+            .0
+            .iter()
+            .zip(&column_widths)
+            .map(|(crate_stack, &width)| centered(&crate_stack.label, width))
+            .collect::<Vec<String>>();
+
+        write!(f, "{}", label_row.join(" "))
+    }
+}
+
+impl Frame for CrateStacks {
+    fn render(&self, width: usize, height: usize) -> Vec<String> {
+        self.0
+            .iter()
+            .take(height)
+            .map(|crate_stack| {
+                let prefix = format!("{:>2} | ", crate_stack.label);
+                let labels = crate_stack
+                    .crates
+                    .iter()
+                    .map(|stacked_crate| stacked_crate.label.as_str())
+                    .collect::<Vec<&str>>()
+                    .join(" ");
+
+                let prefix_width = prefix.chars().count();
+                let label_count = labels.chars().count();
+                if prefix_width + label_count <= width {
+                    return prefix + &labels;
                 }
-            }
-            CratePilingOrder::InOrder => {
-                for piled_on_crate in crates.iter() {
-                    self.0.push(*piled_on_crate);
+                if prefix_width >= width {
+                    return prefix.chars().take(width).collect();
                 }
+
+                // Crates move on and off the top of each stack, so that is the
+                // end kept in view.
+                let kept_labels = labels
+                    .chars()
+                    .skip(label_count - (width - prefix_width - 1))
+                    .collect::<String>();
+
+                format!("{}\u{2026}{}", prefix, kept_labels)
+            })
+            .collect()
+    }
+}
+
+impl Picture for CrateStacks {
+    fn draw(&self) -> Canvas {
+        // Moving crates never changes how many there are, so sizing the canvas
+        // to fit them all in one stack keeps every frame the same height.
+        let crate_count = self
+            .0
+            .iter()
+            .map(|crate_stack| crate_stack.crates.len())
+            .sum::<usize>();
+        let height = crate_count.max(1);
+
+        let mut canvas = Canvas::new(self.0.len() * 2 + 1, height, BACKGROUND_COLOR);
+        for (index, crate_stack) in self.0.iter().enumerate() {
+            for (depth, stacked_crate) in crate_stack.crates.iter().enumerate() {
+                canvas.set(index * 2 + 1, height - depth - 1, stacked_crate.color());
             }
         }
+
+        canvas
     }
+}
+
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub(crate) struct CrateStack {
+    /// Crates in this stack, from the bottom up.
+    pub(crate) crates: Vec<Crate>,
+    /// Label that crate moves refer to this stack by.
+    pub(crate) label: String,
+}
 
-    /// Removes `number_of_crates` from the top of this stack, and returns them.
-    pub(crate) fn pop(&mut self, number_of_crates: usize) -> Vec<Crate> {
-        let first_popped_index = self.0.len() - number_of_crates;
+impl CrateStack {
+    /// Removes `number_of_crates` from the top of this stack, and returns them,
+    /// or returns [None] if this stack does not have that many.
+    pub(crate) fn pop(&mut self, number_of_crates: usize) -> Option<Vec<Crate>> {
+        let first_popped_index = self.crates.len().checked_sub(number_of_crates)?;
 
-        self.0.drain(first_popped_index..).collect::<Vec<Crate>>()
+        Some(
+            self.crates
+                .drain(first_popped_index..)
+                .collect::<Vec<Crate>>(),
+        )
     }
 
     /// Returns the [Crate] at the top of this stack.
     pub(crate) fn top_crate(&self) -> Option<&Crate> {
-        self.0.last()
+        self.crates.last()
+    }
+
+    /// Returns an explanation of why this stack does not have enough crates
+    /// for a move.
+    fn shortage(&self) -> String {
+        match self.crates.len() {
+            1 => format!("stack {} only has 1 crate", self.label),
+            crate_count => format!("stack {} only has {} crates", self.label, crate_count),
+        }
     }
 }
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub(crate) struct Crate {
-    /// Single-character "name" of this [Crate].
-    pub(crate) letter: char,
+    /// "Name" of this [Crate], usually a single letter.
+    pub(crate) label: String,
 }
 
 impl Crate {
     /// Returns the constructed [Crate] that best corresponds to the specified
     /// `encoded_crate`.
-    pub(crate) fn parse(encoded_crate: &str) -> Result<Option<Crate>> {
+    pub(crate) fn parse(encoded_crate: &str) -> Result<Crate> {
         lazy_static! {
           /// Regular expression designed to match strings that look like
-          /// "[a]" or " [B]\n".
+          /// "[a]", " [B]\n", or "[Q7]".
           static ref ENCODED_CRATE_PATTERN: Regex =
-              Regex::new(r"^\s*\[([a-zA-Z])\]\s*$").unwrap();
-
-            /// Regular expression designed to match strings are pure whitespace
-          static ref WHITESPACE_PATTERN: Regex =
-              Regex::new(r"^\s+$").unwrap();
-        }
-
-        if WHITESPACE_PATTERN.is_match(encoded_crate) {
-            return Ok(None);
+              Regex::new(r"^\s*\[([^\s\[\]]+)\]\s*$").unwrap();
         }
 
         let parsed_encoded_crate = ENCODED_CRATE_PATTERN
             .captures(encoded_crate)
             .with_context(|| format!("\"{}\" is not a valid encoded crate", encoded_crate))?;
 
-        let letter = parsed_encoded_crate
+        let label = parsed_encoded_crate
             .get(1)
             .with_context(|| {
                 format!(
@@ -162,12 +382,232 @@ impl Crate {
                 )
             })?
             .as_str()
-            .chars()
-            .next()
-            .with_context(|| {
-                format!("Failed to read the crate letter from \"{}\"", encoded_crate)
-            })?;
+            .to_owned();
+
+        Ok(Crate { label })
+    }
+
+    /// Returns the color this [Crate] is drawn in, which depends on its
+    /// label so that crates can be told apart.
+    fn color(&self) -> Color {
+        let hue = (self.label.chars().map(|c| c as u32).sum::<u32>() % 26) as u8;
+
+        [
+            64 + hue.wrapping_mul(67) % 192,
+            64 + hue.wrapping_mul(29) % 192,
+            224 - hue * 6,
+        ]
+    }
+}
+
+/// Returns every match of `token_pattern` in `row`, with the range of
+/// character positions it spans.
+fn columns<'a>(row: &'a str, token_pattern: &Regex) -> Vec<(&'a str, Range<usize>)> {
+    token_pattern
+        .find_iter(row)
+        .map(|token| {
+            let start = row[..token.start()].chars().count();
+
+            (
+                token.as_str(),
+                start..start + token.as_str().chars().count(),
+            )
+        })
+        .collect()
+}
+
+/// Returns the position of the label, among `labels`, that a crate spanning
+/// `crate_span` sits above: the one nearest its middle, out of those it
+/// overlaps.
+fn stack_index_below(labels: &[(&str, Range<usize>)], crate_span: Range<usize>) -> Option<usize> {
+    // Doubled, so that middles are whole numbers.
+    let middle = |span: &Range<usize>| span.start + span.end;
+
+    labels
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, label_span))| {
+            label_span.start < crate_span.end && crate_span.start < label_span.end
+        })
+        .min_by_key(|(_, (_, label_span))| middle(label_span).abs_diff(middle(&crate_span)))
+        .map(|(index, _)| index)
+}
+
+/// Returns `text` padded with spaces on both sides to `width` characters,
+/// with any odd space on the right.
+fn centered(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(text.chars().count());
+
+    format!(
+        "{}{}{}",
+        " ".repeat(padding / 2),
+        text,
+        " ".repeat(padding - padding / 2)
+    )
+}
+
+/// Color of the space around crates when drawn.
+const BACKGROUND_COLOR: Color = [32, 32, 32];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crate_piling_order::CratePilingOrder;
+    use advent::generators::crate_procedures;
+    use proptest::prelude::*;
+
+    /// Returns the [Crate] with the specified `label`.
+    fn crate_labeled(label: &str) -> Crate {
+        Crate {
+            label: label.to_owned(),
+        }
+    }
+
+    #[test]
+    fn parse_works_given_trimmed_rows() {
+        assert_eq!(
+            CrateStacks::parse("    [D]\n[N] [C]\n 1   2 ").unwrap(),
+            CrateStacks(vec![
+                CrateStack {
+                    crates: vec![crate_labeled("N")],
+                    label: "1".to_owned(),
+                },
+                CrateStack {
+                    crates: vec![crate_labeled("C"), crate_labeled("D")],
+                    label: "2".to_owned(),
+                },
+            ]),
+        );
+    }
+
+    #[test]
+    fn apply_stepwise_names_invalid_moves() {
+        let crate_stacks = CrateStacks::parse("    [D]\n[N] [C]\n 1   2 ").unwrap();
+        let crate_moves = [
+            "move 1 from 2 to 1",
+            "move 3 from 1 to 2",
+            "move 1 from 1 to 3",
+        ]
+        .map(|encoded_crate_move| CrateMove::parse(encoded_crate_move).unwrap());
+
+        let error = crate_stacks
+            .apply_stepwise(&crate_moves, &CratePilingOrder::Flipped, Err, |_, _| {})
+            .unwrap_err();
+        assert_eq!(
+            format!("{:#}", error),
+            "Move 2 (move 3 from 1 to 2) is invalid: stack 1 only has 2 crates"
+        );
+
+        let mut invalid_moves = vec![];
+        let rearranged_crate_stacks = crate_stacks
+            .apply_stepwise(
+                &crate_moves,
+                &CratePilingOrder::Flipped,
+                |error| {
+                    invalid_moves.push(format!("{:#}", error));
+                    Ok(())
+                },
+                |_, _| {},
+            )
+            .unwrap();
+        assert_eq!(
+            invalid_moves,
+            [
+                "Move 2 (move 3 from 1 to 2) is invalid: stack 1 only has 2 crates",
+                "Move 3 (move 1 from 1 to 3) is invalid: there is no stack 3",
+            ]
+        );
+        assert_eq!(
+            rearranged_crate_stacks,
+            CrateStacks::parse("[D]\n[N] [C]\n 1   2 ").unwrap()
+        );
+    }
+
+    #[test]
+    fn render_keeps_the_top_of_each_stack_in_view() {
+        let crate_stacks = CrateStacks::parse("[C]    \n[B]    \n[A] [D]\n 1   2 ").unwrap();
+
+        assert_eq!(crate_stacks.render(80, 24), [" 1 | A B C", " 2 | D"]);
+        assert_eq!(crate_stacks.render(8, 1), [" 1 | \u{2026} C"]);
+        assert_eq!(crate_stacks.render(3, 2), [" 1 ", " 2 "]);
+    }
+
+    #[test]
+    fn display_draws_the_puzzle_diagram() {
+        let encoded_crate_stacks = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 ";
+
+        assert_eq!(
+            CrateStacks::parse(encoded_crate_stacks)
+                .unwrap()
+                .to_string(),
+            encoded_crate_stacks
+        );
+    }
+
+    proptest! {
+        #[test]
+        fn display_round_trips_through_parse(input in crate_procedures()) {
+            let encoded_crate_stacks = input.encode_crate_stacks();
+            let crate_stacks = CrateStacks::parse(&encoded_crate_stacks).unwrap();
+
+            prop_assert_eq!(crate_stacks.to_string(), encoded_crate_stacks);
+            prop_assert_eq!(CrateStacks::parse(&crate_stacks.to_string()).unwrap(), crate_stacks);
+        }
+    }
+
+    #[test]
+    fn parse_places_crates_above_their_labels() {
+        let encoded_crate_stacks = concat!(
+            "                                      [AB]\n",
+            "[A] [B] [C] [D] [E] [F] [G] [H] [I]  [J]  [K]\n",
+            " 1   2   3   4   5   6   7   8   9   10   11 ",
+        );
+        let crate_stacks = CrateStacks::parse(encoded_crate_stacks).unwrap();
+
+        assert_eq!(crate_stacks.0.len(), 11);
+        assert_eq!(
+            crate_stacks.0[9],
+            CrateStack {
+                crates: vec![crate_labeled("J"), crate_labeled("AB")],
+                label: "10".to_owned(),
+            }
+        );
+        assert_eq!(
+            crate_stacks.to_string(),
+            concat!(
+                "                                    [AB]     \n",
+                "[A] [B] [C] [D] [E] [F] [G] [H] [I] [J]  [K] \n",
+                " 1   2   3   4   5   6   7   8   9   10   11 ",
+            )
+        );
+        assert_eq!(
+            CrateStacks::parse(&crate_stacks.to_string()).unwrap(),
+            crate_stacks
+        );
+    }
+
+    #[test]
+    fn apply_move_finds_stacks_by_label() {
+        let mut crate_stacks = CrateStacks::parse("[X]      [Y]\n a  bb  ccc ").unwrap();
+
+        crate_stacks
+            .apply_move(
+                &CrateMove::parse("move 1 from ccc to bb").unwrap(),
+                &CratePilingOrder::Flipped,
+            )
+            .unwrap();
+        assert_eq!(crate_stacks.to_string(), "[X] [Y]       \n a   bb   ccc ");
+    }
+
+    #[test]
+    fn parse_fails_given_crates_without_labels() {
+        assert!(CrateStacks::parse("[A]     [B]\n 1   2 ").is_err());
+        assert!(CrateStacks::parse("[A]\n 1   1 ").is_err());
+    }
 
-        Ok(Some(Crate { letter: letter }))
+    #[test]
+    fn parse_fails_given_floating_crates() {
+        assert!(CrateStacks::parse("[A] [B]\n[C]\n 1   2 ").is_err());
+        assert!(CrateStacks::parse("[A]\n   \n[C]\n 1 ").is_err());
     }
 }
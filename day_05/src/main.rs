@@ -1,53 +1,330 @@
 extern crate advent;
 extern crate anyhow;
+extern crate clap;
 extern crate lazy_static;
 extern crate regex;
 extern crate tokio;
 
+mod crane;
 mod crate_move;
 mod crate_piling_order;
 mod crate_rearrangement_procedure;
 mod crate_stacks;
+mod procedure_search;
+mod rearrangement_history;
 
-use crate::crate_piling_order::CratePilingOrder;
+use crate::crane::{crane_named, Crane};
+use crate::crate_move::CrateMove;
 use crate::crate_rearrangement_procedure::CrateRearrangementProcedure;
-use crate::crate_stacks::Crate;
-use anyhow::{Context, Result};
+use crate::crate_stacks::{Crate, CrateStacks};
+use crate::procedure_search::shortest_procedure;
+use crate::rearrangement_history::RearrangementHistory;
+use advent::{Config, Solution};
+use anyhow::{Context, Error, Result};
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Command line arguments for the "Supply Stacks" puzzle.
+#[derive(Debug, Parser)]
+struct Args {
+    #[command(flatten)]
+    config: Config,
+
+    /// Skips crate moves that cannot be carried out, and reports them, instead
+    /// of failing on the first one.
+    #[arg(long)]
+    lenient: bool,
+
+    /// Specifies which crane carries out the moves, instead of the one for
+    /// the active part: `9000`, `9001`, `9001:N` for a CrateMover 9001 that
+    /// lifts at most `N` crates at a time, or `bottom` for one that moves
+    /// crates from the bottom of the stack.
+    #[arg(long)]
+    crane: Option<String>,
+
+    /// Prints the crate stack diagram before the first move and after every
+    /// move.
+    #[arg(long)]
+    show_steps: bool,
+
+    /// Treats the crate stack diagram as the result of the procedure, and
+    /// prints the diagram it started from instead of printing the answer.
+    #[arg(long)]
+    reverse: bool,
+
+    /// Steps through the procedure, reading `n` (or nothing) to carry out the
+    /// next move, `p` to undo the last one, or `q` to stop, from each line of
+    /// standard input.
+    #[arg(long)]
+    step_through: bool,
+
+    /// Prints the shortest procedure that leaves the crate stacks the same way
+    /// as this one, or as the `--target` diagram, in the same format as the
+    /// puzzle input, instead of printing the answer.
+    #[arg(long)]
+    shortest: bool,
+
+    /// Reads the crate stack diagram that `--shortest` rearranges the crates
+    /// into from the specified path.
+    #[arg(long, requires = "shortest")]
+    target: Option<PathBuf>,
+
+    /// Specifies how many different crate stacks `--shortest` may reach before
+    /// giving up.
+    #[arg(long, default_value_t = 100_000)]
+    state_cap: usize,
+}
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let config = advent::begin();
+    let args = advent::begin_with_args::<Args>();
+    let config = args.config;
 
     let encoded_crate_rearrangement_procedure = advent::data(&config).await?;
 
+    let supply_stacks = SupplyStacks {
+        crane_name: args.crane,
+        lenient: args.lenient,
+    };
+
     let crate_rearrangement_procedure =
         CrateRearrangementProcedure::parse(&encoded_crate_rearrangement_procedure)
             .context("Failed to parse crate rearrangement procedure")?;
 
-    let rearranged_crate_stacks = crate_rearrangement_procedure.execute(match config.part {
-        1 => CratePilingOrder::Flipped,
-        _ => CratePilingOrder::InOrder,
-    });
+    if args.reverse {
+        let crane = supply_stacks.crane(config.part)?;
+
+        println!("{}", crate_rearrangement_procedure.reverse(crane.as_ref())?);
+
+        return Ok(());
+    }
+
+    if args.step_through {
+        let crane = supply_stacks.crane(config.part)?;
+
+        return step_through(&crate_rearrangement_procedure, crane.as_ref());
+    }
+
+    if args.shortest {
+        let crane = supply_stacks.crane(config.part)?;
+
+        let target_crate_stacks = match &args.target {
+            Some(path) => CrateStacks::parse(
+                &std::fs::read_to_string(path)
+                    .with_context(|| format!("Failed to read {}", path.display()))?,
+            )
+            .context("Failed to parse target crate stacks")?,
+            None => {
+                supply_stacks
+                    .rearrange(config.part, &crate_rearrangement_procedure, |_, _| {})?
+                    .0
+            }
+        };
+
+        let crate_moves = shortest_procedure(
+            &crate_rearrangement_procedure.crate_stacks,
+            &target_crate_stacks,
+            crane.as_ref(),
+            args.state_cap,
+        )
+        .context("Failed to find the shortest procedure")?;
+
+        println!(
+            "{}",
+            CrateRearrangementProcedure {
+                crate_moves,
+                crate_stacks: crate_rearrangement_procedure.crate_stacks,
+            }
+        );
+
+        return Ok(());
+    }
+
+    let visualizing = config.visualize || config.render_options.path.is_some();
+
+    if args.show_steps {
+        println!("{}\n", crate_rearrangement_procedure.crate_stacks);
+    }
+
+    let mut frames = vec![crate_rearrangement_procedure.crate_stacks.clone()];
+    let (rearranged_crate_stacks, invalid_moves) = supply_stacks.rearrange(
+        config.part,
+        &crate_rearrangement_procedure,
+        |crate_move, crate_stacks| {
+            if args.show_steps {
+                println!("{}\n\n{}\n", crate_move, crate_stacks);
+            }
+            if visualizing {
+                frames.push(crate_stacks.clone());
+            }
+        },
+    )?;
+
+    for invalid_move in &invalid_moves {
+        eprintln!("Skipped: {:#}", invalid_move);
+    }
+
+    if visualizing {
+        if let Some(path) = &config.render_options.path {
+            return advent::renderer::render(&frames, path, &config.render_options);
+        }
+
+        return advent::visualizer::visualize(&frames);
+    }
+
+    println!(
+        "Top crate letters: {}",
+        top_crate_letters(&rearranged_crate_stacks)?
+    );
 
-    let top_crates = rearranged_crate_stacks
+    Ok(())
+}
+
+/// Solution to the "Supply Stacks" puzzle.
+#[derive(Default)]
+struct SupplyStacks {
+    /// Name of the crane that carries out the moves, if not the one for the
+    /// active part.
+    crane_name: Option<String>,
+    /// Whether crate moves that cannot be carried out are skipped, instead of
+    /// failing the whole procedure.
+    lenient: bool,
+}
+
+impl SupplyStacks {
+    /// Returns the chosen crane, or the one for the specified `part`.
+    fn crane(&self, part: u8) -> Result<Box<dyn Crane>> {
+        crane_named(
+            self.crane_name
+                .as_deref()
+                .unwrap_or_else(|| default_crane_name(part)),
+        )
+    }
+
+    /// Returns the crate stacks that `crate_rearrangement_procedure` leaves
+    /// behind with the chosen crane, or the one for the specified `part`, and
+    /// the error explaining every move that was skipped.
+    ///
+    ///  * `on_step` is invoked with each move carried out and the rearranged
+    ///    crate stacks after it
+    fn rearrange(
+        &self,
+        part: u8,
+        crate_rearrangement_procedure: &CrateRearrangementProcedure,
+        on_step: impl FnMut(&CrateMove, &CrateStacks),
+    ) -> Result<(CrateStacks, Vec<Error>)> {
+        let crane = self.crane(part)?;
+
+        let mut invalid_moves = vec![];
+
+        let rearranged_crate_stacks = crate_rearrangement_procedure
+            .execute_stepwise(
+                crane.as_ref(),
+                |error| {
+                    if !self.lenient {
+                        return Err(error);
+                    }
+
+                    invalid_moves.push(error);
+                    Ok(())
+                },
+                on_step,
+            )
+            .context("Failed to rearrange crates")?;
+
+        Ok((rearranged_crate_stacks, invalid_moves))
+    }
+}
+
+impl Solution for SupplyStacks {
+    fn solve(&self, part: u8, encoded_crate_rearrangement_procedure: &str) -> Result<String> {
+        let crate_rearrangement_procedure =
+            CrateRearrangementProcedure::parse(encoded_crate_rearrangement_procedure)
+                .context("Failed to parse crate rearrangement procedure")?;
+
+        let (rearranged_crate_stacks, _) =
+            self.rearrange(part, &crate_rearrangement_procedure, |_, _| {})?;
+
+        top_crate_letters(&rearranged_crate_stacks)
+    }
+}
+
+/// Steps through `crate_rearrangement_procedure` with `crane` as directed by
+/// each line of standard input, printing the crate stacks after every step.
+fn step_through(
+    crate_rearrangement_procedure: &CrateRearrangementProcedure,
+    crane: &dyn Crane,
+) -> Result<()> {
+    let mut history = RearrangementHistory::before(
+        crate_rearrangement_procedure.crate_stacks.clone(),
+        &crate_rearrangement_procedure.crate_moves,
+        crane,
+    );
+
+    println!("{}\n", history.crate_stacks());
+
+    for line in std::io::stdin().lines() {
+        let step = match line?.trim() {
+            "" | "n" => history.redo().map(|maybe_crate_move| {
+                maybe_crate_move
+                    .map(|crate_move| format!("Move {}: {}", history.done_move_count(), crate_move))
+            }),
+            "p" => history.undo().map(|maybe_crate_move| {
+                maybe_crate_move.map(|crate_move| {
+                    format!(
+                        "Undid move {}: {}",
+                        history.done_move_count() + 1,
+                        crate_move
+                    )
+                })
+            }),
+            "q" => break,
+            command => {
+                eprintln!("\"{}\" is not a command: try n, p, or q", command);
+                continue;
+            }
+        };
+
+        match step {
+            Ok(Some(description)) => println!("{}\n\n{}\n", description, history.crate_stacks()),
+            Ok(None) => eprintln!("There is no move in that direction"),
+            Err(error) => eprintln!("{:#}", error),
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns the label of the crate at the top of each of `crate_stacks`, all
+/// run together.
+fn top_crate_letters(crate_stacks: &CrateStacks) -> Result<String> {
+    let top_crates = crate_stacks
         .top_crates()
         .map(|maybe_top_crate| {
-            maybe_top_crate.with_context(|| {
-                format!(
-                    "One of the crates was missing! {:?}",
-                    rearranged_crate_stacks,
-                )
-            })
+            maybe_top_crate
+                .with_context(|| format!("One of the crates was missing!\n{}", crate_stacks))
         })
         .collect::<Result<Vec<&Crate>>>()
         .context("Failed to pull out top crates")?;
 
     let top_crate_string = top_crates
         .iter()
-        .map(|top_crate| top_crate.letter)
+        .map(|top_crate| top_crate.label.as_str())
         .collect::<String>();
 
-    println!("Top crate letters: {}", top_crate_string);
+    Ok(top_crate_string)
+}
 
-    Ok(())
+/// Returns the name of the crane used in the specified `part`.
+fn default_crane_name(part: u8) -> &'static str {
+    match part {
+        1 => "9000",
+        _ => "9001",
+    }
 }
+
+advent::sample_tests!(SupplyStacks::default());
+advent::reference_tests!(
+    SupplyStacks::default(),
+    advent::generators::crate_procedures()
+);
@@ -0,0 +1,286 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+
+use anyhow::{anyhow, Result};
+
+use crate::{
+    crane::Crane,
+    crate_move::CrateMove,
+    crate_stacks::{CrateStack, CrateStacks},
+};
+
+/// Crate stacks reached during a search, and how they were reached.
+struct SearchNode {
+    /// Crate stacks after every move leading here.
+    crate_stacks: CrateStacks,
+    /// How many moves lead here.
+    move_count: usize,
+    /// Position of the node this one was reached from, with the move that
+    /// reached it, or [None] for the starting crate stacks.
+    previous: Option<(usize, CrateMove)>,
+}
+
+/// Returns the fewest crate moves that rearrange `crate_stacks` into
+/// `target_crate_stacks`, searching outwards with A*.
+///
+/// Every move takes crates off one stack and piles them on another, so no
+/// fewer moves can finish the job than there are stacks still waiting to lose
+/// a crate, or stacks still waiting to gain one. That estimate never drops by
+/// more than one a move, so the first procedure found is also the shortest.
+///
+///  * `crane` carries out each move
+///  * `state_cap` is how many different crate stacks the search may reach
+///    before giving up
+pub(crate) fn shortest_procedure(
+    crate_stacks: &CrateStacks,
+    target_crate_stacks: &CrateStacks,
+    crane: &dyn Crane,
+    state_cap: usize,
+) -> Result<Vec<CrateMove>> {
+    check_rearrangeable(crate_stacks, target_crate_stacks)?;
+
+    let mut nodes = vec![SearchNode {
+        crate_stacks: crate_stacks.clone(),
+        move_count: 0,
+        previous: None,
+    }];
+    let mut best_nodes = HashMap::from([(crate_stacks.clone(), 0)]);
+    let mut frontier = BinaryHeap::from([Reverse((
+        minimum_moves_left(crate_stacks, target_crate_stacks),
+        0,
+    ))]);
+
+    while let Some(Reverse((_, node_index))) = frontier.pop() {
+        let node = &nodes[node_index];
+
+        // Skips nodes for crate stacks that a shorter path reached since.
+        if best_nodes[&node.crate_stacks] != node_index {
+            continue;
+        }
+        if &node.crate_stacks == target_crate_stacks {
+            return Ok(procedure_to(&nodes, node_index));
+        }
+
+        let move_count = node.move_count + 1;
+        let next_crate_stacks = next_moves(&node.crate_stacks)
+            .filter_map(|crate_move| {
+                let mut next_crate_stacks = node.crate_stacks.clone();
+                next_crate_stacks.apply_move(&crate_move, crane).ok()?;
+
+                Some((crate_move, next_crate_stacks))
+            })
+            .collect::<Vec<(CrateMove, CrateStacks)>>();
+
+        for (crate_move, next_crate_stacks) in next_crate_stacks {
+            if let Some(&best_node_index) = best_nodes.get(&next_crate_stacks) {
+                if nodes[best_node_index].move_count <= move_count {
+                    continue;
+                }
+            } else if best_nodes.len() >= state_cap {
+                return Err(anyhow!(
+                    "Gave up after reaching {} different crate stacks",
+                    state_cap
+                ));
+            }
+
+            let estimated_move_count =
+                move_count + minimum_moves_left(&next_crate_stacks, target_crate_stacks);
+
+            best_nodes.insert(next_crate_stacks.clone(), nodes.len());
+            frontier.push(Reverse((estimated_move_count, nodes.len())));
+            nodes.push(SearchNode {
+                crate_stacks: next_crate_stacks,
+                move_count,
+                previous: Some((node_index, crate_move)),
+            });
+        }
+    }
+
+    Err(anyhow!("The crane cannot rearrange the crates that way"))
+}
+
+/// Checks that `crate_stacks` and `target_crate_stacks` have the same stacks,
+/// in the same order, holding the same crates between them.
+fn check_rearrangeable(
+    crate_stacks: &CrateStacks,
+    target_crate_stacks: &CrateStacks,
+) -> Result<()> {
+    let stack_labels = |crate_stacks: &CrateStacks| {
+        crate_stacks
+            .crate_stacks()
+            .iter()
+            .map(|crate_stack| crate_stack.label.clone())
+            .collect::<Vec<String>>()
+    };
+    let crate_labels = |crate_stacks: &CrateStacks| {
+        let mut crate_labels = crate_stacks
+            .crate_stacks()
+            .iter()
+            .flat_map(|crate_stack| &crate_stack.crates)
+            .map(|stacked_crate| stacked_crate.label.clone())
+            .collect::<Vec<String>>();
+        crate_labels.sort();
+
+        crate_labels
+    };
+
+    if stack_labels(crate_stacks) != stack_labels(target_crate_stacks) {
+        return Err(anyhow!("The target crate stacks are labeled differently"));
+    }
+    if crate_labels(crate_stacks) != crate_labels(target_crate_stacks) {
+        return Err(anyhow!("The target crate stacks hold different crates"));
+    }
+
+    Ok(())
+}
+
+/// Returns every move that `crate_stacks` has enough crates for.
+fn next_moves(crate_stacks: &CrateStacks) -> impl Iterator<Item = CrateMove> + '_ {
+    let stacks = crate_stacks.crate_stacks();
+
+    stacks.iter().flat_map(move |origin_crate_stack| {
+        stacks
+            .iter()
+            .filter(move |destination_crate_stack| {
+                destination_crate_stack.label != origin_crate_stack.label
+            })
+            .flat_map(move |destination_crate_stack| {
+                (1..=origin_crate_stack.crates.len()).map(move |number_of_crates| CrateMove {
+                    destination_label: destination_crate_stack.label.clone(),
+                    number_of_crates,
+                    origin_label: origin_crate_stack.label.clone(),
+                })
+            })
+    })
+}
+
+/// Returns a lower bound on how many moves it takes to rearrange
+/// `crate_stacks` into `target_crate_stacks`.
+fn minimum_moves_left(crate_stacks: &CrateStacks, target_crate_stacks: &CrateStacks) -> usize {
+    let (mut losing_stack_count, mut gaining_stack_count) = (0, 0);
+
+    for (crate_stack, target_crate_stack) in crate_stacks
+        .crate_stacks()
+        .iter()
+        .zip(target_crate_stacks.crate_stacks())
+    {
+        // Crates only ever pile on top, so a stack that is not the bottom of
+        // its target has to lose a crate.
+        if !target_crate_stack.crates.starts_with(&crate_stack.crates) {
+            losing_stack_count += 1;
+        }
+        // Crates only ever leave from the top or the bottom, so a stack whose
+        // target is not a run of its crates has to gain a crate.
+        if !contains_run(crate_stack, target_crate_stack) {
+            gaining_stack_count += 1;
+        }
+    }
+
+    losing_stack_count.max(gaining_stack_count)
+}
+
+/// Returns whether the crates of `target_crate_stack` appear in order,
+/// uninterrupted, somewhere in `crate_stack`.
+fn contains_run(crate_stack: &CrateStack, target_crate_stack: &CrateStack) -> bool {
+    target_crate_stack.crates.is_empty()
+        || crate_stack
+            .crates
+            .windows(target_crate_stack.crates.len())
+            .any(|run| run == target_crate_stack.crates)
+}
+
+/// Returns the moves leading from the starting crate stacks to the node at
+/// `node_index`, in order.
+fn procedure_to(nodes: &[SearchNode], mut node_index: usize) -> Vec<CrateMove> {
+    let mut crate_moves = vec![];
+
+    while let Some((previous_node_index, crate_move)) = &nodes[node_index].previous {
+        crate_moves.push(crate_move.clone());
+        node_index = *previous_node_index;
+    }
+    crate_moves.reverse();
+
+    crate_moves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane::crane_named;
+    use crate::crate_rearrangement_procedure::CrateRearrangementProcedure;
+
+    #[test]
+    fn shortest_procedure_beats_the_sample_procedure() {
+        let crate_rearrangement_procedure =
+            CrateRearrangementProcedure::parse(include_str!("../files/sample.txt")).unwrap();
+        let crane = crane_named("9000").unwrap();
+        let rearranged_crate_stacks = crate_rearrangement_procedure
+            .execute_stepwise(crane.as_ref(), Err, |_, _| {})
+            .unwrap();
+
+        let crate_moves = shortest_procedure(
+            &crate_rearrangement_procedure.crate_stacks,
+            &rearranged_crate_stacks,
+            crane.as_ref(),
+            100_000,
+        )
+        .unwrap();
+
+        assert_eq!(crate_moves.len(), 3);
+
+        let shortest_crate_rearrangement_procedure = CrateRearrangementProcedure {
+            crate_moves,
+            crate_stacks: crate_rearrangement_procedure.crate_stacks,
+        };
+        assert_eq!(
+            shortest_crate_rearrangement_procedure
+                .execute_stepwise(crane.as_ref(), Err, |_, _| {})
+                .unwrap(),
+            rearranged_crate_stacks
+        );
+        assert_eq!(
+            CrateRearrangementProcedure::parse(&shortest_crate_rearrangement_procedure.to_string())
+                .unwrap(),
+            shortest_crate_rearrangement_procedure
+        );
+    }
+
+    #[test]
+    fn shortest_procedure_depends_on_the_crane() {
+        let crate_stacks = CrateStacks::parse("[B]    \n[A]    \n 1   2 ").unwrap();
+        let target_crate_stacks = CrateStacks::parse("[A]    \n[B]    \n 1   2 ").unwrap();
+
+        let move_count = |name: &str| {
+            shortest_procedure(
+                &crate_stacks,
+                &target_crate_stacks,
+                crane_named(name).unwrap().as_ref(),
+                100,
+            )
+            .map(|crate_moves| crate_moves.len())
+        };
+
+        assert_eq!(move_count("9001").unwrap(), 3);
+        assert_eq!(move_count("bottom").unwrap(), 2);
+        // Moving one crate at a time between two stacks never reorders them.
+        assert_eq!(
+            move_count("9000").unwrap_err().to_string(),
+            "The crane cannot rearrange the crates that way"
+        );
+
+        assert!(shortest_procedure(
+            &crate_stacks,
+            &target_crate_stacks,
+            crane_named("9001").unwrap().as_ref(),
+            2
+        )
+        .is_err());
+        assert!(shortest_procedure(
+            &crate_stacks,
+            &CrateStacks::parse("[A]    \n 1   2 ").unwrap(),
+            crane_named("9001").unwrap().as_ref(),
+            100
+        )
+        .is_err());
+    }
+}
@@ -0,0 +1,184 @@
+use anyhow::Result;
+
+use crate::{crane::Crane, crate_move::CrateMove, crate_stacks::CrateStacks};
+
+/// Crate stacks partway through a rearrangement procedure, which can step
+/// forwards by redoing the next move, or backwards by undoing the last one.
+pub(crate) struct RearrangementHistory<'a> {
+    /// Crane that carries out every move.
+    crane: &'a dyn Crane,
+    /// Crate stacks after every done move.
+    crate_stacks: CrateStacks,
+    /// Moves carried out so far, oldest first.
+    done_moves: Vec<CrateMove>,
+    /// Moves still to be carried out, next one last.
+    undone_moves: Vec<CrateMove>,
+}
+
+impl<'a> RearrangementHistory<'a> {
+    /// Returns the [RearrangementHistory] of `crate_stacks` before `crane`
+    /// carries out any of `crate_moves`.
+    pub(crate) fn before(
+        crate_stacks: CrateStacks,
+        crate_moves: &[CrateMove],
+        crane: &'a dyn Crane,
+    ) -> RearrangementHistory<'a> {
+        RearrangementHistory {
+            crane,
+            crate_stacks,
+            done_moves: vec![],
+            undone_moves: crate_moves.iter().rev().cloned().collect(),
+        }
+    }
+
+    /// Returns the [RearrangementHistory] of `crate_stacks` after `crane` has
+    /// carried out every one of `crate_moves`.
+    pub(crate) fn after(
+        crate_stacks: CrateStacks,
+        crate_moves: &[CrateMove],
+        crane: &'a dyn Crane,
+    ) -> RearrangementHistory<'a> {
+        RearrangementHistory {
+            crane,
+            crate_stacks,
+            done_moves: crate_moves.to_vec(),
+            undone_moves: vec![],
+        }
+    }
+
+    /// Returns the crate stacks after every done move.
+    pub(crate) fn crate_stacks(&self) -> &CrateStacks {
+        &self.crate_stacks
+    }
+
+    /// Returns how many moves have been carried out so far.
+    pub(crate) fn done_move_count(&self) -> usize {
+        self.done_moves.len()
+    }
+
+    /// Carries out the next move and returns it, or returns [None] if every
+    /// move is done.
+    pub(crate) fn redo(&mut self) -> Result<Option<CrateMove>> {
+        let Some(crate_move) = self.undone_moves.pop() else {
+            return Ok(None);
+        };
+
+        if let Err(error) = self.crate_stacks.apply_move(&crate_move, self.crane) {
+            let error = error.context(format!(
+                "Move {} ({}) is invalid",
+                self.done_moves.len() + 1,
+                crate_move
+            ));
+            self.undone_moves.push(crate_move);
+
+            return Err(error);
+        }
+
+        self.done_moves.push(crate_move);
+
+        Ok(self.done_moves.last().cloned())
+    }
+
+    /// Reverts the last move carried out and returns it, or returns [None] if
+    /// no move is done.
+    pub(crate) fn undo(&mut self) -> Result<Option<CrateMove>> {
+        let Some(crate_move) = self.done_moves.pop() else {
+            return Ok(None);
+        };
+
+        if let Err(error) = self.crate_stacks.undo_move(&crate_move, self.crane) {
+            let error = error.context(format!(
+                "Move {} ({}) cannot be undone",
+                self.done_moves.len() + 1,
+                crate_move
+            ));
+            self.done_moves.push(crate_move);
+
+            return Err(error);
+        }
+
+        self.undone_moves.push(crate_move);
+
+        Ok(self.undone_moves.last().cloned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crane::crane_named;
+    use crate::crate_rearrangement_procedure::CrateRearrangementProcedure;
+    use advent::generators::{crate_procedures, GeneratedInput};
+    use proptest::prelude::*;
+
+    #[test]
+    fn undo_and_redo_step_through_the_procedure() {
+        let crate_rearrangement_procedure =
+            CrateRearrangementProcedure::parse(include_str!("../files/sample.txt")).unwrap();
+        let crane = crane_named("9000").unwrap();
+
+        let mut history = RearrangementHistory::before(
+            crate_rearrangement_procedure.crate_stacks.clone(),
+            &crate_rearrangement_procedure.crate_moves,
+            crane.as_ref(),
+        );
+        assert_eq!(history.undo().unwrap(), None);
+
+        while history.redo().unwrap().is_some() {}
+        assert_eq!(history.done_move_count(), 4);
+        let rearranged_crate_stacks = history.crate_stacks().clone();
+
+        assert_eq!(
+            history.undo().unwrap().unwrap().to_string(),
+            "move 1 from 1 to 2"
+        );
+        history.undo().unwrap();
+        history.redo().unwrap();
+        history.redo().unwrap();
+        assert_eq!(history.redo().unwrap(), None);
+        assert_eq!(history.crate_stacks(), &rearranged_crate_stacks);
+    }
+
+    #[test]
+    fn undo_fails_given_inconsistent_stacks() {
+        let crate_rearrangement_procedure =
+            CrateRearrangementProcedure::parse("[A]\n 1   2 \n\nmove 1 from 1 to 2").unwrap();
+        let crane = crane_named("9001").unwrap();
+
+        let mut history = RearrangementHistory::after(
+            crate_rearrangement_procedure.crate_stacks.clone(),
+            &crate_rearrangement_procedure.crate_moves,
+            crane.as_ref(),
+        );
+
+        assert_eq!(
+            format!("{:#}", history.undo().unwrap_err()),
+            "Move 1 (move 1 from 1 to 2) cannot be undone: stack 2 only has 0 crates"
+        );
+        assert_eq!(history.done_move_count(), 1);
+    }
+
+    proptest! {
+        #[test]
+        fn reverse_undoes_execute(input in crate_procedures()) {
+            let crate_rearrangement_procedure =
+                CrateRearrangementProcedure::parse(&input.encode()).unwrap();
+
+            for name in ["9000", "9001", "9001:2", "bottom"] {
+                let crane = crane_named(name).unwrap();
+
+                let rearranged_crate_procedure = CrateRearrangementProcedure {
+                    crate_moves: crate_rearrangement_procedure.crate_moves.clone(),
+                    crate_stacks: crate_rearrangement_procedure
+                        .execute_stepwise(crane.as_ref(), Err, |_, _| {})
+                        .unwrap(),
+                };
+
+                prop_assert_eq!(
+                    &rearranged_crate_procedure.reverse(crane.as_ref()).unwrap(),
+                    &crate_rearrangement_procedure.crate_stacks
+                );
+            }
+        }
+    }
+}
@@ -57,6 +57,8 @@ impl DataStream {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use advent::generators::{data_streams, GeneratedInput};
+    use proptest::prelude::*;
 
     #[test]
     fn parse_reads_data_streams_correctly() {
@@ -86,4 +88,17 @@ mod tests {
             None
         );
     }
+
+    proptest! {
+        #[test]
+        fn index_after_unique_span_agrees_with_naive_implementation(
+            input in data_streams(),
+            span_length in 1..=14usize,
+        ) {
+            prop_assert_eq!(
+                DataStream::parse(&input.encode()).index_after_unique_span(span_length),
+                input.index_after_unique_span_naively(span_length)
+            );
+        }
+    }
 }
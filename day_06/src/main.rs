@@ -6,6 +6,7 @@ extern crate tokio;
 
 mod data_stream;
 
+use advent::Solution;
 use anyhow::{Context, Result};
 use data_stream::DataStream;
 
@@ -15,24 +16,41 @@ async fn main() -> Result<()> {
 
     let encoded_data_stream = advent::data(&config).await?;
 
-    let data_stream = DataStream::parse(&encoded_data_stream);
+    let answer = TuningTrouble.solve(config.part, &encoded_data_stream)?;
 
     match config.part {
-        1 => {
-            let start_of_packet_index = data_stream
-                .start_of_packet_index()
-                .context("Encoded data stream did not have a start of packet marker")?;
+        1 => println!("Start of packet index: {}", answer),
+        _ => println!("Start of message index: {}", answer),
+    }
 
-            println!("Start of packet index: {}", start_of_packet_index);
-        }
-        _ => {
-            let start_of_message_index = data_stream
-                .start_of_message_index()
-                .context("Encoded data stream did not have a start of message marker")?;
+    Ok(())
+}
+
+/// Solution to the "Tuning Trouble" puzzle.
+struct TuningTrouble;
+
+impl Solution for TuningTrouble {
+    fn solve(&self, part: u8, encoded_data_stream: &str) -> Result<String> {
+        let data_stream = DataStream::parse(encoded_data_stream);
 
-            println!("Start of message index: {}", start_of_message_index);
+        match part {
+            1 => {
+                let start_of_packet_index = data_stream
+                    .start_of_packet_index()
+                    .context("Encoded data stream did not have a start of packet marker")?;
+
+                Ok(start_of_packet_index.to_string())
+            }
+            _ => {
+                let start_of_message_index = data_stream
+                    .start_of_message_index()
+                    .context("Encoded data stream did not have a start of message marker")?;
+
+                Ok(start_of_message_index.to_string())
+            }
         }
     }
-
-    Ok(())
 }
+
+advent::sample_tests!(TuningTrouble);
+advent::reference_tests!(TuningTrouble, advent::generators::data_streams());
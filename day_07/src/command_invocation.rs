@@ -40,9 +40,7 @@ impl CommandInvocation {
         if let Some(cd) = ENCODED_CD_PATTERN.captures(&shell_exchange.input) {
             let directory_path = cd.get(1).unwrap().as_str().to_owned();
 
-            return Ok(CommandInvocation::ChangeDirectory {
-                directory_path: directory_path,
-            });
+            return Ok(CommandInvocation::ChangeDirectory { directory_path });
         }
 
         if ENCODED_LS_PATTERN.is_match(&shell_exchange.input) {
@@ -53,9 +51,7 @@ impl CommandInvocation {
                 .collect::<Result<Vec<DirectoryEntry>>>()
                 .context("Failed to parse directory entries")?;
 
-            return Ok(CommandInvocation::ListDirectoryContents {
-                directory_entries: directory_entries,
-            });
+            return Ok(CommandInvocation::ListDirectoryContents { directory_entries });
         }
 
         Err(anyhow!(
@@ -65,6 +61,28 @@ impl CommandInvocation {
     }
 }
 
+/// Returns the [CommandInvocation] behind each shell exchange in
+/// `terminal_output`, which starts every command with a `$`.
+pub(crate) fn parse_command_invocations(terminal_output: &str) -> Result<Vec<CommandInvocation>> {
+    lazy_static! {
+      /// Regular expression designed to match lines filled with
+      /// whitespace.
+      static ref WHITESPACE_LINE_PATTERN: Regex =
+          Regex::new(r"^\s*$").unwrap();
+    }
+
+    terminal_output
+        .split('$')
+        .filter(|encoded_shell_exchange| !WHITESPACE_LINE_PATTERN.is_match(encoded_shell_exchange))
+        .map(ShellExchange::parse)
+        .collect::<Result<Vec<ShellExchange>>>()
+        .context("Failed to read shell exchanges")?
+        .iter()
+        .map(CommandInvocation::from)
+        .collect::<Result<Vec<CommandInvocation>>>()
+        .context("Failed to read command invocations")
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -43,21 +43,18 @@ impl DirectoryEntry {
                 .parse::<u32>()
                 .with_context(|| format!("\"{}\" is not a valid file size", raw_size))?;
 
-            return Ok(DirectoryEntry::File {
-                name: name,
-                size: size,
-            });
+            return Ok(DirectoryEntry::File { name, size });
         }
 
         if let Some(directory_entry) = DIRECTORY_PATTERN.captures(encoded_directory_entry) {
             let name = directory_entry.get(1).unwrap().as_str().to_owned();
 
-            return Ok(DirectoryEntry::Subdirectory { name: name });
+            return Ok(DirectoryEntry::Subdirectory { name });
         }
 
-        return Err(anyhow!(
+        Err(anyhow!(
             "\"{}\" is not a valid directory entry",
             encoded_directory_entry
-        ));
+        ))
     }
 }
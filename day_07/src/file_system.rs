@@ -56,8 +56,8 @@ impl FileSystem {
         nodes.push(FileSystemNode::new_root(current_node_id));
 
         FileSystem {
-            current_node_id: current_node_id,
-            nodes: nodes,
+            current_node_id,
+            nodes,
             root_node_id: current_node_id,
         }
     }
@@ -89,7 +89,7 @@ impl FileSystem {
         } else {
             let mut size: u32 = 0;
             for child_id in node.child_ids.iter() {
-                size = size + self.furnish_node_size_tuples(*child_id, node_size_tuples)
+                size += self.furnish_node_size_tuples(*child_id, node_size_tuples)
             }
 
             size
@@ -119,7 +119,7 @@ impl FileSystem {
             .child_ids
             .push(next_node_id);
 
-        return next_node_id;
+        next_node_id
     }
 
     /// Creates a new directory within the current directory named `name`,
@@ -136,7 +136,7 @@ impl FileSystem {
             .child_ids
             .push(next_node_id);
 
-        return next_node_id;
+        next_node_id
     }
 
     /// Navigates to the directory named "name", creating it if it does not
@@ -165,7 +165,7 @@ impl FileSystem {
                 }
 
                 maybe_node = node.parent_id.map(|parent_id| &self.nodes[parent_id]);
-                i = i + 1;
+                i += 1;
             }
 
             if let Some(node) = maybe_node {
@@ -202,7 +202,7 @@ impl FileSystem {
 
         self.current_node_id = next_node_id;
 
-        return Ok(did_create_new_directory);
+        Ok(did_create_new_directory)
     }
 
     /// Creates a new file within the current directory named `name` if it does
@@ -264,8 +264,7 @@ impl FileSystem {
             .child_ids
             .iter()
             .map(|child_id| &self.nodes[*child_id])
-            .filter(|child| child.name == name)
-            .next()
+            .find(|child| child.name == name)
     }
 }
 
@@ -302,9 +301,9 @@ impl FileSystemNode {
     fn new_directory(id: usize, name: String, parent_id: Option<usize>) -> FileSystemNode {
         FileSystemNode {
             child_ids: Vec::with_capacity(ESTIMATED_ENTRIES_PER_DIRECTORY),
-            id: id,
-            name: name,
-            parent_id: parent_id,
+            id,
+            name,
+            parent_id,
             size: None,
         }
     }
@@ -319,8 +318,8 @@ impl FileSystemNode {
     fn new_file(id: usize, name: String, parent_id: usize, size: u32) -> FileSystemNode {
         FileSystemNode {
             child_ids: Vec::with_capacity(ESTIMATED_ENTRIES_PER_DIRECTORY),
-            id: id,
-            name: name,
+            id,
+            name,
             parent_id: Some(parent_id),
             size: Some(size),
         }
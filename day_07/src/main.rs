@@ -9,12 +9,10 @@ mod directory_entry;
 mod file_system;
 mod shell_exchange;
 
+use advent::Solution;
 use anyhow::{Context, Result};
-use command_invocation::CommandInvocation;
+use command_invocation::parse_command_invocations;
 use file_system::FileSystem;
-use lazy_static::lazy_static;
-use regex::Regex;
-use shell_exchange::ShellExchange;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -22,73 +20,90 @@ async fn main() -> Result<()> {
 
     let terminal_output = advent::data(&config).await?;
 
-    let command_invocations = terminal_output
-        .split("$")
-        .filter(|encoded_shell_exchange| {
-            lazy_static! {
-              /// Regular expression designed to match lines filled with
-              /// whitespace.
-              static ref WHITESPACE_LINE_PATTERN: Regex =
-                  Regex::new(r"^\s*$").unwrap();
+    let answer = NoSpaceLeftOnDevice.solve(config.part, &terminal_output)?;
+
+    match config.part {
+        1 => println!(
+            "Total size of all directories smaller than 100000: {}",
+            answer
+        ),
+        _ => println!("We can delete directory with size: {}", answer),
+    }
+
+    Ok(())
+}
+
+/// Solution to the "No Space Left On Device" puzzle.
+struct NoSpaceLeftOnDevice;
+
+impl Solution for NoSpaceLeftOnDevice {
+    fn solve(&self, part: u8, terminal_output: &str) -> Result<String> {
+        let command_invocations = parse_command_invocations(terminal_output)?;
+
+        let file_system = FileSystem::build_imperatively(command_invocations)
+            .context("Failed to assemble file system from imperative commands")?;
+
+        let mut file_system_sizes = file_system.sizes();
+
+        match part {
+            1 => {
+                let total_size_of_slender_directories = file_system_sizes
+                    .iter()
+                    .filter(|(node, size)| node.is_directory() && *size <= 100000)
+                    .fold(0, |acc, (_, size)| acc + *size);
+
+                Ok(total_size_of_slender_directories.to_string())
             }
+            _ => {
+                let total_size = file_system_sizes
+                    .iter()
+                    .rev()
+                    .filter(|(node, _)| node.name == "/")
+                    .map(|(_, size)| *size)
+                    .next()
+                    .context("Failed to derive \"/\" size total")?;
 
-            !WHITESPACE_LINE_PATTERN.is_match(encoded_shell_exchange)
-        })
-        .map(|encoded_shell_exchange| ShellExchange::parse(encoded_shell_exchange))
-        .collect::<Result<Vec<ShellExchange>>>()
-        .context("Failed to read shell exchanges")?
-        .iter()
-        .map(|shell_exchange| CommandInvocation::from(shell_exchange))
-        .collect::<Result<Vec<CommandInvocation>>>()
-        .context("Failed to read command invocations")?;
+                let remaining_space =
+                    70_000_000_u32.checked_sub(total_size).with_context(|| {
+                        format!(
+                            "Files totalling {} exceed the 70000000 space on the device",
+                            total_size
+                        )
+                    })?;
 
-    let file_system = FileSystem::build_imperatively(command_invocations)
-        .context("Failed to assemble file system from imperative commands")?;
+                file_system_sizes.sort_by_key(|a| a.1);
 
-    let mut file_system_sizes = file_system.sizes();
+                let size_of_directory_to_delete = file_system_sizes
+                    .iter()
+                    .filter(|(node, size)| {
+                        node.is_directory() && (remaining_space + *size) >= 30_000_000
+                    })
+                    .map(|(_, size)| *size)
+                    .next()
+                    .context("Could find a directory to delete")?;
 
-    match config.part {
-        1 => {
-            let total_size_of_slender_directories = file_system_sizes
-                .iter()
-                .filter(|(node, size)| node.is_directory() && *size <= 100000)
-                .fold(0, |acc, (_, size)| acc + *size);
-
-            println!(
-                "Total size of all directories smaller than 100000: {}",
-                total_size_of_slender_directories
-            );
-        }
-        _ => {
-            let total_size = file_system_sizes
-                .iter()
-                .rev()
-                .filter(|(node, _)| node.name == "/")
-                .map(|(_, size)| *size)
-                .next()
-                .context("Failed to derive \"/\" size total")?;
-            println!("Total size: {}", total_size);
-
-            let remaining_space = 70_000_000 - total_size;
-            println!("Remaining space: {}", remaining_space);
-
-            file_system_sizes.sort_by(|a, b| a.1.cmp(&b.1));
-
-            let size_of_directory_to_delete = file_system_sizes
-                .iter()
-                .filter(|(node, size)| {
-                    node.is_directory() && (remaining_space + *size) >= 30_000_000
-                })
-                .map(|(_, size)| *size)
-                .next()
-                .context("Could find a directory to delete")?;
-
-            println!(
-                "We can delete directory with size: {}",
-                size_of_directory_to_delete
-            );
+                Ok(size_of_directory_to_delete.to_string())
+            }
         }
     }
+}
 
-    Ok(())
+advent::sample_tests!(NoSpaceLeftOnDevice);
+advent::reference_tests!(NoSpaceLeftOnDevice, advent::generators::shell_transcripts());
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn solve_rejects_files_larger_than_the_device() {
+        let error = NoSpaceLeftOnDevice
+            .solve(2, "$ cd /\n$ ls\n70000001 a.txt\n")
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "Files totalling 70000001 exceed the 70000000 space on the device"
+        );
+    }
 }
@@ -6,6 +6,7 @@ extern crate tokio;
 
 mod tree_grid;
 
+use advent::Solution;
 use anyhow::{Context, Result};
 use tree_grid::TreeGrid;
 
@@ -15,30 +16,38 @@ async fn main() -> Result<()> {
 
     let encoded_tree_grid = advent::data(&config).await?;
 
-    let tree_grid = TreeGrid::parse(&encoded_tree_grid).context("Failed to parse tree grid")?;
+    let answer = TreetopTreeHouse.solve(config.part, &encoded_tree_grid)?;
 
     match config.part {
-        1 => {
-            let mut visible_trees = tree_grid
-                .visible_trees()
-                .into_iter()
-                .collect::<Vec<(usize, usize)>>();
-
-            visible_trees.sort();
-
-            println!("# of visible trees: {}", visible_trees.len());
-        }
-        _ => {
-            let mut scenic_scores = tree_grid.scenic_scores();
+        1 => println!("# of visible trees: {}", answer),
+        _ => println!("Highest possible scenic score: {}", answer),
+    }
 
-            scenic_scores.sort_by(|a, b| b.scenic_score.cmp(&a.scenic_score));
+    Ok(())
+}
 
-            println!(
-                "Highest possible scenic score: {}",
-                scenic_scores[0].scenic_score
-            );
+/// Solution to the "Treetop Tree House" puzzle.
+struct TreetopTreeHouse;
+
+impl Solution for TreetopTreeHouse {
+    fn solve(&self, part: u8, encoded_tree_grid: &str) -> Result<String> {
+        let tree_grid = TreeGrid::parse(encoded_tree_grid).context("Failed to parse tree grid")?;
+
+        match part {
+            1 => Ok(tree_grid.visible_trees().len().to_string()),
+            _ => {
+                let highest_scenic_score = tree_grid
+                    .scenic_scores()
+                    .iter()
+                    .map(|scenic_score_evaluation| scenic_score_evaluation.scenic_score)
+                    .max()
+                    .context("Tree grid has no trees")?;
+
+                Ok(highest_scenic_score.to_string())
+            }
         }
     }
-
-    Ok(())
 }
+
+advent::sample_tests!(TreetopTreeHouse);
+advent::reference_tests!(TreetopTreeHouse, advent::generators::tree_grids());
@@ -79,11 +79,11 @@ impl TreeGrid {
             .collect::<Vec<u32>>();
 
         Ok(TreeGrid {
-            column_count: column_count,
-            column_maxima: column_maxima,
-            row_count: row_count,
-            row_maxima: row_maxima,
-            tree_height_rows: tree_height_rows,
+            column_count,
+            column_maxima,
+            row_count,
+            row_maxima,
+            tree_height_rows,
         })
     }
 
@@ -99,7 +99,7 @@ impl TreeGrid {
 
                 let mut down_tree_count: usize = 0;
                 for other_row_index in row_index + 1..self.row_count {
-                    down_tree_count = down_tree_count + 1;
+                    down_tree_count += 1;
 
                     if tree_height <= self.tree_height_rows[other_row_index][column_index] {
                         break;
@@ -108,7 +108,7 @@ impl TreeGrid {
 
                 let mut left_tree_count: usize = 0;
                 for other_column_index in (0..column_index).rev() {
-                    left_tree_count = left_tree_count + 1;
+                    left_tree_count += 1;
 
                     if tree_height <= self.tree_height_rows[row_index][other_column_index] {
                         break;
@@ -117,7 +117,7 @@ impl TreeGrid {
 
                 let mut right_tree_count: usize = 0;
                 for other_column_index in column_index + 1..self.column_count {
-                    right_tree_count = right_tree_count + 1;
+                    right_tree_count += 1;
 
                     if tree_height <= self.tree_height_rows[row_index][other_column_index] {
                         break;
@@ -126,7 +126,7 @@ impl TreeGrid {
 
                 let mut up_tree_count: usize = 0;
                 for other_row_index in (0..row_index).rev() {
-                    up_tree_count = up_tree_count + 1;
+                    up_tree_count += 1;
 
                     if tree_height <= self.tree_height_rows[other_row_index][column_index] {
                         break;
@@ -138,7 +138,7 @@ impl TreeGrid {
 
                 scenic_scores.push(ScenicScoreEvaluation {
                     coordinate: (column_index, row_index),
-                    scenic_score: scenic_score,
+                    scenic_score,
                     viewing_distances: (
                         up_tree_count,
                         right_tree_count,
@@ -174,6 +174,8 @@ impl TreeGrid {
                     break;
                 }
             }
+
+            tallest_so_far = 0;
             for row_index in (0..self.row_count).rev() {
                 let tree_height = self.tree_height_rows[row_index][column_index];
 
@@ -206,10 +208,12 @@ impl TreeGrid {
                     break;
                 }
             }
+
+            tallest_so_far = 0;
             for column_index in (0..self.column_count).rev() {
                 let tree_height = self.tree_height_rows[row_index][column_index];
 
-                if column_index == self.row_count - 1 || tallest_so_far < tree_height {
+                if column_index == self.column_count - 1 || tallest_so_far < tree_height {
                     visible_trees.insert((column_index, row_index));
 
                     tallest_so_far = tree_height;
@@ -236,3 +240,27 @@ pub(crate) struct ScenicScoreEvaluation {
     /// Number of visible trees in each direction (up, right, bottom, left).
     pub(crate) viewing_distances: (usize, usize, usize, usize),
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent::generators::{tree_grids, GeneratedInput};
+    use proptest::prelude::*;
+
+    #[test]
+    fn visible_trees_sees_past_shorter_trees_from_the_far_edges() {
+        let tree_grid = TreeGrid::parse("959\n919\n939\n929\n909").unwrap();
+
+        assert!(tree_grid.visible_trees().contains(&(1, 2)));
+    }
+
+    proptest! {
+        #[test]
+        fn visible_trees_agrees_with_naive_implementation(input in tree_grids()) {
+            prop_assert_eq!(
+                TreeGrid::parse(&input.encode()).unwrap().visible_trees(),
+                input.visible_trees_naively()
+            );
+        }
+    }
+}
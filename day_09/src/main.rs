@@ -7,9 +7,10 @@ extern crate tokio;
 mod rope;
 mod rope_move;
 
+use advent::Solution;
 use anyhow::{Context, Result};
-use rope::Rope;
-use rope_move::RopeMove;
+use rope::{Rope, RopeFrame};
+use rope_move::parse_rope_moves;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -17,23 +18,76 @@ async fn main() -> Result<()> {
 
     let encoded_rope_moves = advent::data(&config).await?;
 
-    let rope_moves = encoded_rope_moves
-        .lines()
-        .map(|encoded_rope_move| RopeMove::parse(encoded_rope_move))
-        .collect::<Result<Vec<RopeMove>>>()
-        .context("Failed to read rope moves")?;
+    if config.visualize || config.render_options.path.is_some() {
+        let rope_moves = parse_rope_moves(&encoded_rope_moves)?;
 
-    let knot_count = match config.part {
-        1 => 2,
-        _ => 10,
-    };
+        let mut rope = Rope::new(knot_count(config.part)).context("Failed to create a rope")?;
+        let mut knot_position_history = vec![rope.knot_positions.clone()];
+        for rope_move in rope_moves {
+            rope.move_head_stepwise(&rope_move, |rope| {
+                knot_position_history.push(rope.knot_positions.clone())
+            });
+        }
+
+        let tail_trail = knot_position_history
+            .iter()
+            .map(|knot_positions| knot_positions[knot_positions.len() - 1])
+            .collect::<Vec<(i32, i32)>>();
+
+        let bounds = knot_position_history.iter().flatten().fold(
+            ((0, 0), (0, 0)),
+            |((min_x, min_y), (max_x, max_y)), &(x, y)| {
+                ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y)))
+            },
+        );
+
+        let frames = knot_position_history
+            .into_iter()
+            .enumerate()
+            .map(|(i, knot_positions)| RopeFrame {
+                bounds,
+                knot_positions,
+                tail_trail: &tail_trail[..=i],
+            })
+            .collect::<Vec<RopeFrame>>();
 
-    let mut rope = Rope::new(knot_count).context("Failed to create a rope")?;
-    for rope_move in rope_moves {
-        rope.move_head(&rope_move);
+        if let Some(path) = &config.render_options.path {
+            return advent::renderer::render(&frames, path, &config.render_options);
+        }
+
+        return advent::visualizer::visualize(&frames);
     }
 
-    println!("# of tail positions: {:?}", rope.tail_positions.len());
+    let answer = RopeBridge.solve(config.part, &encoded_rope_moves)?;
+
+    println!("# of tail positions: {}", answer);
 
     Ok(())
 }
+
+/// Solution to the "Rope Bridge" puzzle.
+struct RopeBridge;
+
+impl Solution for RopeBridge {
+    fn solve(&self, part: u8, encoded_rope_moves: &str) -> Result<String> {
+        let rope_moves = parse_rope_moves(encoded_rope_moves)?;
+
+        let mut rope = Rope::new(knot_count(part)).context("Failed to create a rope")?;
+        for rope_move in rope_moves {
+            rope.move_head(&rope_move);
+        }
+
+        Ok(rope.tail_positions.len().to_string())
+    }
+}
+
+/// Returns how many knots the rope has in the specified `part`.
+fn knot_count(part: u8) -> usize {
+    match part {
+        1 => 2,
+        _ => 10,
+    }
+}
+
+advent::sample_tests!(RopeBridge);
+advent::reference_tests!(RopeBridge, advent::generators::rope_moves());
@@ -1,5 +1,7 @@
 use std::collections::HashSet;
 
+use advent::renderer::{Canvas, Color, Picture};
+use advent::visualizer::Frame;
 use anyhow::{anyhow, Result};
 
 use crate::rope_move::{RopeMove, RopeMoveDirection};
@@ -30,31 +32,42 @@ impl Rope {
         tail_positions.insert(starting_position);
 
         Ok(Rope {
-            knot_positions: knot_positions,
-            tail_positions: tail_positions,
+            knot_positions,
+            tail_positions,
         })
     }
 
     /// Applies the provided [RopeMove] to this [Rope]'s head knot, moving the
     /// other knots thereafter if necessary.
     pub(crate) fn move_head(&mut self, rope_move: &RopeMove) {
+        self.move_head_stepwise(rope_move, |_| {});
+    }
+
+    /// Applies the provided [RopeMove] to this [Rope]'s head knot one step at
+    /// a time, moving the other knots thereafter if necessary.
+    ///
+    /// * `on_step` is invoked with this [Rope] after each step
+    pub(crate) fn move_head_stepwise(
+        &mut self,
+        rope_move: &RopeMove,
+        mut on_step: impl FnMut(&Rope),
+    ) {
         let RopeMove {
             direction,
             distance,
         } = rope_move;
 
         let knot_count = self.knot_positions.len();
-        let tail_positions = &mut self.tail_positions;
 
         for _ in 0..*distance {
             {
                 let head_knot_position = &mut self.knot_positions[0];
 
                 match direction {
-                    RopeMoveDirection::Down => head_knot_position.1 = head_knot_position.1 - 1,
-                    RopeMoveDirection::Left => head_knot_position.0 = head_knot_position.0 - 1,
-                    RopeMoveDirection::Right => head_knot_position.0 = head_knot_position.0 + 1,
-                    RopeMoveDirection::Up => head_knot_position.1 = head_knot_position.1 + 1,
+                    RopeMoveDirection::Down => head_knot_position.1 -= 1,
+                    RopeMoveDirection::Left => head_knot_position.0 -= 1,
+                    RopeMoveDirection::Right => head_knot_position.0 += 1,
+                    RopeMoveDirection::Up => head_knot_position.1 += 1,
                 }
             };
 
@@ -69,19 +82,111 @@ impl Rope {
                 }
 
                 if previous_knot_x - knot_position.0 > 0 {
-                    knot_position.0 = knot_position.0 + 1;
+                    knot_position.0 += 1;
                 } else if delta_x < 0 {
-                    knot_position.0 = knot_position.0 - 1;
+                    knot_position.0 -= 1;
                 }
 
                 if previous_knot_y - knot_position.1 > 0 {
-                    knot_position.1 = knot_position.1 + 1;
+                    knot_position.1 += 1;
                 } else if delta_y < 0 {
-                    knot_position.1 = knot_position.1 - 1;
+                    knot_position.1 -= 1;
                 }
             }
 
-            tail_positions.insert(self.knot_positions[self.knot_positions.len() - 1]);
+            self.tail_positions
+                .insert(self.knot_positions[self.knot_positions.len() - 1]);
+
+            on_step(self);
+        }
+    }
+}
+
+/// Snapshot of a [Rope] after a single step, along with the trail its tail
+/// has left so far.
+pub(crate) struct RopeFrame<'a> {
+    /// Lowest and highest positions any knot reaches over the whole
+    /// simulation.
+    pub(crate) bounds: ((i32, i32), (i32, i32)),
+    /// Positions of each knot in the rope.
+    pub(crate) knot_positions: Vec<(i32, i32)>,
+    /// Every position the last knot has had up to and including this step.
+    pub(crate) tail_trail: &'a [(i32, i32)],
+}
+
+impl Frame for RopeFrame<'_> {
+    fn render(&self, width: usize, height: usize) -> Vec<String> {
+        let tail_trail = self.tail_trail.iter().collect::<HashSet<&(i32, i32)>>();
+
+        // Keep the head in the middle of the view.
+        let (head_x, head_y) = self.knot_positions[0];
+        let left_x = head_x - (width / 2) as i32;
+        let top_y = head_y + (height / 2) as i32;
+
+        (0..height as i32)
+            .map(|row| {
+                (0..width as i32)
+                    .map(|column| {
+                        let position = (left_x + column, top_y - row);
+
+                        match self
+                            .knot_positions
+                            .iter()
+                            .position(|knot_position| *knot_position == position)
+                        {
+                            Some(0) => 'H',
+                            Some(index) => char::from_digit(index as u32, 36).unwrap_or('*'),
+                            None if tail_trail.contains(&position) => '#',
+                            None if position == (0, 0) => 's',
+                            None => '.',
+                        }
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+}
+
+impl Picture for RopeFrame<'_> {
+    fn draw(&self) -> Canvas {
+        // Drawing the whole area the rope ever covers keeps the view steady
+        // from one frame to the next.
+        let ((min_x, min_y), (max_x, max_y)) = self.bounds;
+        let pixel = |(x, y): (i32, i32)| ((x - min_x) as usize, (max_y - y) as usize);
+
+        let mut canvas = Canvas::new(
+            (max_x - min_x + 1) as usize,
+            (max_y - min_y + 1) as usize,
+            BACKGROUND_COLOR,
+        );
+
+        for position in self.tail_trail {
+            let (x, y) = pixel(*position);
+            canvas.set(x, y, TRAIL_COLOR);
         }
+
+        // Knots further along the rope are drawn first so that the head ends
+        // up on top.
+        for position in self.knot_positions.iter().skip(1).rev() {
+            let (x, y) = pixel(*position);
+            canvas.set(x, y, KNOT_COLOR);
+        }
+
+        let (x, y) = pixel(self.knot_positions[0]);
+        canvas.set(x, y, HEAD_COLOR);
+
+        canvas
     }
 }
+
+/// Color of positions that no knot occupies or has occupied when drawn.
+const BACKGROUND_COLOR: Color = [16, 16, 32];
+
+/// Color of the head knot when drawn.
+const HEAD_COLOR: Color = [255, 96, 64];
+
+/// Color of knots other than the head when drawn.
+const KNOT_COLOR: Color = [255, 208, 64];
+
+/// Color of positions the tail has visited when drawn.
+const TRAIL_COLOR: Color = [64, 128, 160];
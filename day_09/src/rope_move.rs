@@ -42,8 +42,8 @@ impl RopeMove {
             .with_context(|| format!("\"{}\" is not a valid rope move distance", raw_distance))?;
 
         Ok(RopeMove {
-            direction: direction,
-            distance: distance,
+            direction,
+            distance,
         })
     }
 }
@@ -77,3 +77,12 @@ impl RopeMoveDirection {
         }
     }
 }
+
+/// Returns the [RopeMove] instances listed in `encoded_rope_moves`.
+pub(crate) fn parse_rope_moves(encoded_rope_moves: &str) -> Result<Vec<RopeMove>> {
+    encoded_rope_moves
+        .lines()
+        .map(RopeMove::parse)
+        .collect::<Result<Vec<RopeMove>>>()
+        .context("Failed to read rope moves")
+}
@@ -1,10 +1,13 @@
+use advent::renderer::{Canvas, Picture};
+use advent::visualizer::Frame;
+
 use crate::{instruction::Instruction, screen::Screen};
 
 /// Executes instructions.
 #[derive(Debug)]
 pub(crate) struct Computer<F>
 where
-    F: FnMut(i64) -> (),
+    F: FnMut(i64),
 {
     /// How many cycles have completed.
     cycle: u64,
@@ -18,7 +21,7 @@ where
 
 impl<F> Computer<F>
 where
-    F: FnMut(i64) -> (),
+    F: FnMut(i64),
 {
     /// Creates and returns a new [Computer].
     ///
@@ -27,7 +30,7 @@ where
     pub(crate) fn new(on_signal_strength: F) -> Computer<F> {
         Computer {
             cycle: 0,
-            on_signal_strength: on_signal_strength,
+            on_signal_strength,
             register: 1,
             screen: Screen::new(40, 6, 3),
         }
@@ -35,24 +38,48 @@ where
 
     /// Executes the given `instruction`.
     pub(crate) fn compute(&mut self, instruction: Instruction) {
+        self.compute_stepwise(instruction, |_| {});
+    }
+
+    /// Executes the given `instruction` one cycle at a time.
+    ///
+    /// * `on_tick` is invoked with this [Computer] after each cycle
+    pub(crate) fn compute_stepwise(
+        &mut self,
+        instruction: Instruction,
+        mut on_tick: impl FnMut(&Self),
+    ) {
         match instruction {
             Instruction::Add(integer) => {
                 self.tick();
+                on_tick(self);
                 self.tick();
+                on_tick(self);
 
                 self.register += integer;
             }
             Instruction::NoOp => {
                 self.tick();
+                on_tick(self);
             }
         }
     }
 
+    /// Returns a [ComputerFrame] depicting the current state of this
+    /// [Computer].
+    pub(crate) fn snapshot(&self) -> ComputerFrame {
+        ComputerFrame {
+            cycle: self.cycle,
+            register: self.register,
+            screen: self.screen.clone(),
+        }
+    }
+
     /// Advances the instruction clock.
     fn tick(&mut self) {
-        self.cycle = self.cycle + 1;
+        self.cycle += 1;
 
-        if self.cycle == 20 || (self.cycle > 20 && ((self.cycle - 20) % 40 == 0)) {
+        if self.cycle == 20 || (self.cycle > 20 && (self.cycle - 20).is_multiple_of(40)) {
             let signal_strength = (self.cycle as i64) * self.register;
 
             (self.on_signal_strength)(signal_strength)
@@ -61,3 +88,72 @@ where
         self.screen.paint(self.register);
     }
 }
+
+/// Snapshot of a [Computer] after a single cycle.
+#[derive(Debug)]
+pub(crate) struct ComputerFrame {
+    /// How many cycles had completed.
+    cycle: u64,
+    /// State of the [Computer].
+    register: i64,
+    /// Screen that the [Computer] had written to.
+    screen: Screen,
+}
+
+impl Frame for ComputerFrame {
+    fn render(&self, width: usize, height: usize) -> Vec<String> {
+        let screen = self.screen.to_string();
+        let screen_lines = screen.lines().collect::<Vec<&str>>();
+
+        // Scroll the screen just far enough to keep the row being painted in
+        // view below the status lines.
+        let screen_height = height.saturating_sub(2);
+        let first_row = (self.screen.cursor_row() + 1).saturating_sub(screen_height);
+
+        [
+            format!("Cycle {}  X={}", self.cycle, self.register),
+            String::new(),
+        ]
+        .into_iter()
+        .chain(
+            screen_lines
+                .iter()
+                .skip(first_row)
+                .take(screen_height)
+                .map(|line| line.to_string()),
+        )
+        .take(height)
+        .map(|line| line.chars().take(width).collect())
+        .collect()
+    }
+}
+
+impl Picture for ComputerFrame {
+    fn draw(&self) -> Canvas {
+        self.screen.draw()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn render_scrolls_to_the_row_being_painted() {
+        let mut screen = Screen::new(4, 3, 3);
+        for _ in 0..9 {
+            screen.paint(10);
+        }
+        let computer_frame = ComputerFrame {
+            cycle: 9,
+            register: 10,
+            screen,
+        };
+
+        assert_eq!(
+            computer_frame.render(80, 24),
+            ["Cycle 9  X=10", "", "....", "....", ".###"]
+        );
+        assert_eq!(computer_frame.render(6, 3), ["Cycle ", "", ".###"]);
+    }
+}
@@ -30,7 +30,7 @@ impl Instruction {
               Regex::new(r"^\s*noop\s*$").unwrap();
         }
 
-        if let Some(encoded_add) = ENCODED_ADD_PATTERN.captures(&encoded_instruction) {
+        if let Some(encoded_add) = ENCODED_ADD_PATTERN.captures(encoded_instruction) {
             let raw_integer = encoded_add.get(1).unwrap().as_str();
 
             let integer = raw_integer
@@ -48,3 +48,12 @@ impl Instruction {
         }
     }
 }
+
+/// Returns the [Instruction] instances listed in `encoded_instructions`.
+pub(crate) fn parse_instructions(encoded_instructions: &str) -> Result<Vec<Instruction>> {
+    encoded_instructions
+        .lines()
+        .map(Instruction::parse)
+        .collect::<Result<Vec<Instruction>>>()
+        .context("Failed to interpret instructions")
+}
@@ -8,8 +8,9 @@ extern crate lazy_static;
 extern crate regex;
 extern crate tokio;
 
-use anyhow::{Context, Result};
-use instruction::Instruction;
+use advent::Solution;
+use anyhow::Result;
+use instruction::parse_instructions;
 
 use computer::Computer;
 
@@ -19,35 +20,62 @@ async fn main() -> Result<()> {
 
     let encoded_instructions = advent::data(&config).await?;
 
-    let instructions = encoded_instructions
-        .lines()
-        .map(|encoded_instruction| Instruction::parse(encoded_instruction))
-        .collect::<Result<Vec<Instruction>>>()
-        .context("Failed to interpret instructions")?;
+    if config.visualize || config.render_options.path.is_some() {
+        let instructions = parse_instructions(&encoded_instructions)?;
 
-    let mut i = 0;
-    let mut total_signal_strength: i64 = 0;
-
-    let mut computer = Computer::new(|signal_strength| {
-        if i < 6 {
-            total_signal_strength = total_signal_strength + signal_strength;
+        let mut computer = Computer::new(|_| {});
+        let mut frames = vec![computer.snapshot()];
+        for instruction in instructions {
+            computer.compute_stepwise(instruction, |computer| frames.push(computer.snapshot()));
         }
 
-        i = i + 1;
-    });
+        if let Some(path) = &config.render_options.path {
+            return advent::renderer::render(&frames, path, &config.render_options);
+        }
 
-    for instruction in instructions {
-        computer.compute(instruction);
+        return advent::visualizer::visualize(&frames);
     }
 
+    let answer = CathodeRayTube.solve(config.part, &encoded_instructions)?;
+
     match config.part {
-        1 => {
-            println!("Total signal strength: {}", total_signal_strength);
-        }
-        _ => {
-            println!("Screen:\n{}", computer.screen);
-        }
+        1 => println!("Total signal strength: {}", answer),
+        _ => println!("Screen:\n{}", answer),
     }
 
     Ok(())
 }
+
+/// Solution to the "Cathode-Ray Tube" puzzle.
+struct CathodeRayTube;
+
+impl Solution for CathodeRayTube {
+    fn solve(&self, part: u8, encoded_instructions: &str) -> Result<String> {
+        let instructions = parse_instructions(encoded_instructions)?;
+
+        let mut i = 0;
+        let mut total_signal_strength: i64 = 0;
+
+        let mut computer = Computer::new(|signal_strength| {
+            if i < 6 {
+                total_signal_strength += signal_strength;
+            }
+
+            i += 1;
+        });
+
+        for instruction in instructions {
+            computer.compute(instruction);
+        }
+
+        let screen = computer.screen.to_string();
+
+        match part {
+            1 => Ok(total_signal_strength.to_string()),
+            _ => Ok(screen),
+        }
+    }
+}
+
+advent::sample_tests!(CathodeRayTube);
+advent::reference_tests!(CathodeRayTube, advent::generators::cpu_programs());
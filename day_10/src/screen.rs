@@ -1,7 +1,9 @@
 use std::fmt::Display;
 
+use advent::renderer::{Canvas, Color, Picture};
+
 /// CRT screen that updates once per cycle.
-#[derive(Debug)]
+#[derive(Clone, Debug)]
 pub(crate) struct Screen {
     /// How many columns of pixels this [Screen] has.
     columns: usize,
@@ -21,7 +23,7 @@ impl Screen {
     /// * `sprite_width` is how many pixels the sprite occupies horizaontally
     pub(crate) fn new(columns: usize, rows: usize, sprite_width: usize) -> Screen {
         Screen {
-            columns: columns,
+            columns,
             cursor: 0,
             half_sprite_width: (sprite_width as f64) / 2.0,
             pixels: vec![LIT_PIXEL; columns * rows],
@@ -31,12 +33,9 @@ impl Screen {
     /// Paints the current pixel, deciding what it looks like based on the value
     /// of `sprite_position` and advances to the next one.
     pub(crate) fn paint(&mut self, sprite_position: i64) {
-        let sprite_displacement = ((self.cursor as i64) - sprite_position).abs() as f64;
+        let column = self.cursor % self.columns;
+        let sprite_displacement = ((column as i64) - sprite_position).abs() as f64;
 
-        println!(
-            "paint: {}/{} -> {}",
-            sprite_position, self.cursor, sprite_displacement
-        );
         let pixel = if sprite_displacement <= self.half_sprite_width {
             LIT_PIXEL
         } else {
@@ -48,6 +47,14 @@ impl Screen {
 
         self.cursor += 1;
     }
+
+    /// Returns the index of the row holding the pixel painted last, or the
+    /// first row if none has been painted yet.
+    pub(crate) fn cursor_row(&self) -> usize {
+        let rows = self.pixels.len() / self.columns;
+
+        (self.cursor.saturating_sub(1) / self.columns) % rows
+    }
 }
 
 impl Display for Screen {
@@ -65,8 +72,45 @@ impl Display for Screen {
     }
 }
 
+impl Picture for Screen {
+    fn draw(&self) -> Canvas {
+        let rows = self.pixels.len() / self.columns;
+
+        let mut canvas = Canvas::new(self.columns, rows, DARK_COLOR);
+        for (index, pixel) in self.pixels.iter().enumerate() {
+            if *pixel == LIT_PIXEL {
+                canvas.set(index % self.columns, index / self.columns, LIT_COLOR);
+            }
+        }
+
+        canvas
+    }
+}
+
+/// Color of an unilluminated pixel when drawn.
+const DARK_COLOR: Color = [24, 16, 8];
+
+/// Color of an illuminated pixel when drawn.
+const LIT_COLOR: Color = [255, 176, 0];
+
 /// Character used to represent a unilluminated pixel.
 const DARK_PIXEL: char = '.';
 
 /// Character used to represent an illuminated pixel.
 const LIT_PIXEL: char = '#';
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paint_compares_the_sprite_against_the_column_on_every_row() {
+        let mut screen = Screen::new(3, 2, 3);
+
+        for _ in 0..6 {
+            screen.paint(0);
+        }
+
+        assert_eq!(screen.to_string(), "##.\n##.\n");
+    }
+}
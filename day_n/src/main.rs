@@ -4,6 +4,7 @@ extern crate lazy_static;
 extern crate regex;
 extern crate tokio;
 
+use advent::Solution;
 use anyhow::Result;
 
 #[tokio::main]
@@ -12,7 +13,20 @@ async fn main() -> Result<()> {
 
     let data = advent::data(&config).await?;
 
-    println!("Hello advent! {}", data);
+    let answer = Puzzle.solve(config.part, &data)?;
+
+    println!("Hello advent! {}", answer);
 
     Ok(())
 }
+
+/// Solution to this advent day's puzzle.
+struct Puzzle;
+
+impl Solution for Puzzle {
+    fn solve(&self, _part: u8, data: &str) -> Result<String> {
+        Ok(data.to_owned())
+    }
+}
+
+advent::sample_tests!(Puzzle);
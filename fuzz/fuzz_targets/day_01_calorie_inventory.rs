@@ -0,0 +1,31 @@
+#![no_main]
+// Parser modules are shared with the day, which does not use all of them.
+#![allow(dead_code)]
+
+#[path = "../../day_01/src/calorie_inventory.rs"]
+mod calorie_inventory;
+#[path = "../../day_01/src/inventory_format.rs"]
+mod inventory_format;
+#[path = "../../day_01/src/inventory_separator.rs"]
+mod inventory_separator;
+
+use inventory_format::InventoryFormat;
+use inventory_separator::InventorySeparator;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let data = String::from_utf8_lossy(data);
+
+    for (format, separator) in [
+        (InventoryFormat::Csv, InventorySeparator::BlankLine),
+        (InventoryFormat::Json, InventorySeparator::BlankLine),
+        (InventoryFormat::Text, InventorySeparator::BlankLine),
+        (InventoryFormat::Text, InventorySeparator::Column),
+    ] {
+        for elf_inventory in format.read(&data, &separator) {
+            if elf_inventory.is_err() {
+                break;
+            }
+        }
+    }
+});
@@ -0,0 +1,43 @@
+#![no_main]
+// Parser modules are shared with the day, which does not use all of them.
+#![allow(dead_code)]
+
+#[path = "../../day_02/src/cipher.rs"]
+mod cipher;
+
+#[path = "../../day_02/src/hand_game.rs"]
+mod hand_game;
+
+#[path = "../../day_02/src/hand_shape.rs"]
+mod hand_shape;
+
+#[path = "../../day_02/src/round.rs"]
+mod round;
+
+#[path = "../../day_02/src/round_outcome.rs"]
+mod round_outcome;
+
+#[path = "../../day_02/src/scorable.rs"]
+mod scorable;
+
+#[path = "../../day_02/src/strategy_guide.rs"]
+mod strategy_guide;
+
+#[path = "../../day_02/src/strategy_guide_interpretation.rs"]
+mod strategy_guide_interpretation;
+
+use libfuzzer_sys::fuzz_target;
+use strategy_guide_interpretation::StrategyGuideInterpretation;
+
+fuzz_target!(|data: &[u8]| {
+    let data = String::from_utf8_lossy(data);
+
+    let game = hand_game::HandGame::rock_paper_scissors();
+
+    for interpretation in [
+        StrategyGuideInterpretation::hand_shape(game),
+        StrategyGuideInterpretation::round_outcome(game),
+    ] {
+        let _ = strategy_guide::StrategyGuide::parse(&data, &interpretation);
+    }
+});
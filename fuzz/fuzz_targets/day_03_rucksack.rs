@@ -0,0 +1,21 @@
+#![no_main]
+// Parser modules are shared with the day, which does not use all of them.
+#![allow(dead_code)]
+
+#[path = "../../day_03/src/item_set.rs"]
+mod item_set;
+
+#[path = "../../day_03/src/priorities.rs"]
+mod priorities;
+
+#[path = "../../day_03/src/rucksack.rs"]
+mod rucksack;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let data = String::from_utf8_lossy(data);
+    let priority_table = priorities::PriorityTable::default();
+
+    let _ = rucksack::parse_rucksacks(&data, 2, &priority_table);
+});
@@ -0,0 +1,22 @@
+#![no_main]
+// Parser modules are shared with the day, which does not use all of them.
+#![allow(dead_code)]
+
+#[path = "../../day_04/src/section_assignment.rs"]
+mod section_assignment;
+
+#[path = "../../day_04/src/section_assignment_group.rs"]
+mod section_assignment_group;
+
+#[path = "../../day_04/src/section_assignment_pair.rs"]
+mod section_assignment_pair;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let data = String::from_utf8_lossy(data);
+
+    for any_group_size in [false, true] {
+        let _ = section_assignment_group::parse_section_assignment_groups(&data, any_group_size);
+    }
+});
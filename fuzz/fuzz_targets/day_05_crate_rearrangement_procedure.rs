@@ -0,0 +1,29 @@
+#![no_main]
+// Parser modules are shared with the day, which does not use all of them.
+#![allow(dead_code)]
+
+#[path = "../../day_05/src/crane.rs"]
+mod crane;
+
+#[path = "../../day_05/src/crate_move.rs"]
+mod crate_move;
+
+#[path = "../../day_05/src/crate_piling_order.rs"]
+mod crate_piling_order;
+
+#[path = "../../day_05/src/crate_rearrangement_procedure.rs"]
+mod crate_rearrangement_procedure;
+
+#[path = "../../day_05/src/crate_stacks.rs"]
+mod crate_stacks;
+
+#[path = "../../day_05/src/rearrangement_history.rs"]
+mod rearrangement_history;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let data = String::from_utf8_lossy(data);
+
+    let _ = crate_rearrangement_procedure::CrateRearrangementProcedure::parse(&data);
+});
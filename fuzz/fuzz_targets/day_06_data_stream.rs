@@ -0,0 +1,14 @@
+#![no_main]
+// Parser modules are shared with the day, which does not use all of them.
+#![allow(dead_code)]
+
+#[path = "../../day_06/src/data_stream.rs"]
+mod data_stream;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let data = String::from_utf8_lossy(data);
+
+    let _ = data_stream::DataStream::parse(&data);
+});
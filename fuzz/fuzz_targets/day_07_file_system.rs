@@ -0,0 +1,25 @@
+#![no_main]
+// Parser modules are shared with the day, which does not use all of them.
+#![allow(dead_code)]
+
+#[path = "../../day_07/src/command_invocation.rs"]
+mod command_invocation;
+
+#[path = "../../day_07/src/directory_entry.rs"]
+mod directory_entry;
+
+#[path = "../../day_07/src/file_system.rs"]
+mod file_system;
+
+#[path = "../../day_07/src/shell_exchange.rs"]
+mod shell_exchange;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let data = String::from_utf8_lossy(data);
+
+    if let Ok(command_invocations) = command_invocation::parse_command_invocations(&data) {
+        let _ = file_system::FileSystem::build_imperatively(command_invocations);
+    }
+});
@@ -0,0 +1,14 @@
+#![no_main]
+// Parser modules are shared with the day, which does not use all of them.
+#![allow(dead_code)]
+
+#[path = "../../day_09/src/rope_move.rs"]
+mod rope_move;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let data = String::from_utf8_lossy(data);
+
+    let _ = rope_move::parse_rope_moves(&data);
+});
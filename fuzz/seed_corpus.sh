@@ -0,0 +1,19 @@
+#!/bin/sh
+# Seeds the corpus of every fuzz target with the puzzle data of its day.
+set -e
+
+cd "$(dirname "$0")"
+
+for fuzz_target in fuzz_targets/*.rs; do
+    name=$(basename "$fuzz_target" .rs)
+    day=$(echo "$name" | cut -c1-6)
+
+    mkdir -p "corpus/$name"
+
+    for data_file in ../"$day"/files/*.txt; do
+        case "$data_file" in
+            *.answer.txt) ;;
+            *) cp "$data_file" "corpus/$name/$day-$(basename "$data_file")" ;;
+        esac
+    done
+done